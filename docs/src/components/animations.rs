@@ -39,18 +39,18 @@ fn BasicValueAnimation() -> Element {
         if *is_visible.read() {
             opacity.animate_to(
                 1.0,
-                AnimationConfig::new(AnimationMode::Tween(Tween {
-                    duration: std::time::Duration::from_millis(500),
-                    easing: easer::functions::Cubic::ease_in_out,
-                })),
+                AnimationConfig::new(AnimationMode::Tween(
+                    Tween::new(std::time::Duration::from_millis(500))
+                        .with_easing(easer::functions::Cubic::ease_in_out),
+                )),
             );
         } else {
             opacity.animate_to(
                 0.0,
-                AnimationConfig::new(AnimationMode::Tween(Tween {
-                    duration: std::time::Duration::from_millis(500),
-                    easing: easer::functions::Cubic::ease_in_out,
-                })),
+                AnimationConfig::new(AnimationMode::Tween(
+                    Tween::new(std::time::Duration::from_millis(500))
+                        .with_easing(easer::functions::Cubic::ease_in_out),
+                )),
             );
         }
     });
@@ -84,6 +84,7 @@ fn TransformAnimation() -> Element {
                     damping: 10.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             );
         } else {
@@ -94,6 +95,7 @@ fn TransformAnimation() -> Element {
                     damping: 10.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             );
         }
@@ -206,6 +208,7 @@ fn CustomColorAnimation() -> Element {
                     damping: 10.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             );
         } else {
@@ -220,6 +223,7 @@ fn CustomColorAnimation() -> Element {
                     damping: 10.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             );
         }
@@ -263,6 +267,7 @@ fn SequenceAnimation() -> Element {
                 damping: 12.0,
                 mass: 1.0,
                 velocity: 10.0,
+                ..Default::default()
             })),
         );
 
@@ -348,10 +353,10 @@ let mut opacity = use_motion(0.0f32);
 use_effect(move || {
     opacity.animate_to(
         1.0,
-        AnimationConfig::new(AnimationMode::Tween(Tween {
-            duration: Duration::from_millis(500),
-            easing: easer::functions::Cubic::ease_in_out,
-        })),
+        AnimationConfig::new(AnimationMode::Tween(
+            Tween::new(Duration::from_millis(500))
+                .with_easing(easer::functions::Cubic::ease_in_out),
+        )),
     );
 });
 
@@ -397,6 +402,7 @@ fn TransformAnimation() -> Element {
                     damping: 10.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             );
         } else {
@@ -407,6 +413,7 @@ fn TransformAnimation() -> Element {
                     damping: 10.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             );
         }
@@ -458,16 +465,17 @@ transform.animate_to(
         damping: 10.0,
         mass: 1.0,
         velocity: 0.0,
+        ..Default::default()
     })),
 );
 
 // Or use tween for precise timing
 transform.animate_to(
     Transform::new(0.0, 0.0, 1.0, 0.0),
-    AnimationConfig::new(AnimationMode::Tween(Tween {
-        duration: Duration::from_millis(300),
-        easing: easer::functions::Cubic::ease_out,
-    })),
+    AnimationConfig::new(AnimationMode::Tween(
+        Tween::new(Duration::from_millis(300))
+            .with_easing(easer::functions::Cubic::ease_out),
+    )),
 );"#.to_string(),
                 TransformAnimation {}
             }
@@ -561,6 +569,7 @@ let onclick = move |_| {
                 damping: 12.0,
                 mass: 1.0,
                 velocity: 10.0,
+                ..Default::default()
             }))
         );
 
@@ -659,10 +668,10 @@ fn AdvancedFeaturesAnimation() -> Element {
     let start_infinite = move |_| {
         infinite_value.animate_to(
             1.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_millis(1000),
-                easing: easer::functions::Cubic::ease_in_out,
-            }))
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_millis(1000))
+                    .with_easing(easer::functions::Cubic::ease_in_out),
+            ))
             .with_loop(LoopMode::Infinite),
         );
     };
@@ -680,10 +689,10 @@ fn AdvancedFeaturesAnimation() -> Element {
     let start_callback = move |_| {
         callback_value.animate_to(
             1.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_millis(1000),
-                easing: easer::functions::Cubic::ease_in_out,
-            }))
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_millis(1000))
+                    .with_easing(easer::functions::Cubic::ease_in_out),
+            ))
             .with_loop(LoopMode::Times(3))
             .with_on_complete(|| println!("Animation completed after 3 loops!")),
         );
@@ -693,24 +702,24 @@ fn AdvancedFeaturesAnimation() -> Element {
     let reset_all = move |_| {
         infinite_value.animate_to(
             0.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_millis(500),
-                easing: easer::functions::Cubic::ease_out,
-            })),
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_millis(500))
+                    .with_easing(easer::functions::Cubic::ease_out),
+            )),
         );
         delayed_value.animate_to(
             0.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_millis(500),
-                easing: easer::functions::Cubic::ease_out,
-            })),
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_millis(500))
+                    .with_easing(easer::functions::Cubic::ease_out),
+            )),
         );
         callback_value.animate_to(
             0.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_millis(500),
-                easing: easer::functions::Cubic::ease_out,
-            })),
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_millis(500))
+                    .with_easing(easer::functions::Cubic::ease_out),
+            )),
         );
     };
 
@@ -39,15 +39,16 @@ pub fn NavBar() -> Element {
                 damping: 20.0,
                 mass: 1.0,
                 velocity: 0.0,
+                ..Default::default()
             })),
         );
 
         nav_opacity.animate_to(
             1.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_millis(300),
-                easing: easer::functions::Cubic::ease_out,
-            })),
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_millis(300))
+                    .with_easing(easer::functions::Cubic::ease_out),
+            )),
         );
     });
 
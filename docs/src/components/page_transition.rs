@@ -272,6 +272,7 @@ fn App() -> Element {
         damping: 30.0,     // Higher = less bounce
         mass: 0.8,         // Lower = more responsive
         velocity: 0.0,     // Initial velocity
+        ..Default::default()
     });
 
     // Provide spring context to all child components
@@ -363,6 +364,7 @@ fn AdminSection() -> Element {
         damping: 20.0,
         mass: 0.8,
         velocity: 0.0,
+        ..Default::default()
     });
 
     use_context_provider(|| admin_spring);
@@ -380,6 +382,7 @@ fn UserSection() -> Element {
         damping: 35.0,
         mass: 1.0,
         velocity: 0.0,
+        ..Default::default()
     });
 
     use_context_provider(|| user_spring);
@@ -67,9 +67,9 @@ fn main() {
         use_context_provider(|| resolver);
 
         // To use a Tween for page transitions, provide it via context:
-        let tween = use_signal(|| Tween {
-            duration: std::time::Duration::from_millis(500),
-            easing: easer::functions::Cubic::ease_in_out,
+        let tween = use_signal(|| {
+            Tween::new(std::time::Duration::from_millis(500))
+                .with_easing(easer::functions::Cubic::ease_in_out)
         });
         use_context_provider(|| tween);
 
@@ -16,6 +16,7 @@ pub fn AnimatedCounter() -> Element {
                 damping: 12.0,
                 mass: 1.0,
                 velocity: 10.0,
+                ..Default::default()
             })),
         );
 
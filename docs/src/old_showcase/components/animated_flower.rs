@@ -110,6 +110,7 @@ pub fn AnimatedFlower() -> Element {
                 damping: 8.0,
                 mass: 0.4,
                 velocity: 0.5,
+                ..Default::default()
             })),
         );
 
@@ -121,6 +122,7 @@ pub fn AnimatedFlower() -> Element {
                 damping: 3.0,
                 mass: 0.3,
                 velocity: 0.0,
+                ..Default::default()
             }))
             .with_loop(LoopMode::Alternate),
         );
@@ -138,6 +140,7 @@ pub fn AnimatedFlower() -> Element {
                 damping: 6.0,
                 mass: 0.4,
                 velocity: 2.5,
+                ..Default::default()
             }))
             .with_on_complete(move || {
                 is_leaves_grown.set(true);
@@ -155,6 +158,7 @@ pub fn AnimatedFlower() -> Element {
                     damping: 7.0,
                     mass: 0.4,
                     velocity: 1.5,
+                    ..Default::default()
                 }))
                 .with_loop(LoopMode::Alternate),
             );
@@ -167,6 +171,7 @@ pub fn AnimatedFlower() -> Element {
                     damping: 5.0,
                     mass: 0.3,
                     velocity: 0.5,
+                    ..Default::default()
                 }))
                 .with_loop(LoopMode::Infinite),
             );
@@ -179,6 +184,7 @@ pub fn AnimatedFlower() -> Element {
                     damping: 12.0,   // Increased damping
                     mass: 1.0,       // Increased mass
                     velocity: 0.0,   // Start with zero velocity
+                    ..Default::default()
                 }))
                 .with_loop(LoopMode::Alternate),
             );
@@ -191,6 +197,7 @@ pub fn AnimatedFlower() -> Element {
                     damping: 6.0,
                     mass: 0.5,
                     velocity: 0.0,
+                    ..Default::default()
                 }))
                 .with_loop(LoopMode::Alternate),
             );
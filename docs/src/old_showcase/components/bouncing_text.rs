@@ -15,10 +15,9 @@ fn BouncingLetter(letter: char, delay: f32) -> Element {
                 rotation: 5.0 * (std::f32::consts::PI / 180.0),
                 x: 0.0,
             },
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_secs(1),
-                easing: easer::functions::Sine::ease_in_out,
-            }))
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_secs(1)).with_easing(easer::functions::Sine::ease_in_out),
+            ))
             .with_loop(LoopMode::Infinite)
             .with_delay(delay),
         );
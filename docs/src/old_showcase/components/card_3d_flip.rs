@@ -15,6 +15,7 @@ pub fn Card3DFlip() -> Element {
                     damping: 20.0,    // Increased for less oscillation
                     mass: 0.8,        // Reduced for lighter feel
                     velocity: 5.0,    // Reduced for smoother start
+                    ..Default::default()
                 })),
             );
         } else {
@@ -30,6 +31,7 @@ pub fn Card3DFlip() -> Element {
                     damping: 20.0,    // Increased for less oscillation
                     mass: 0.8,        // Reduced for lighter feel
                     velocity: 5.0,    // Reduced for smoother start
+                    ..Default::default()
                 })),
             );
         }
@@ -246,6 +246,7 @@ pub fn SwingingCube() -> Element {
                 damping: 8.0,    // Adjusted damping for better bounce
                 mass: 1.2,       // Increased mass for more weight
                 velocity: 3.0,   // Faster initial velocity
+                ..Default::default()
             }))
             .with_loop(LoopMode::Alternate), // Makes the animation go back and forth
         );
@@ -258,6 +259,7 @@ pub fn SwingingCube() -> Element {
                 damping: 5.0,
                 mass: 1.0,
                 velocity: 0.0,
+                ..Default::default()
             }))
             .with_loop(LoopMode::Alternate),
         );
@@ -269,6 +271,7 @@ pub fn SwingingCube() -> Element {
                 damping: 6.0,
                 mass: 0.8,
                 velocity: 0.0,
+                ..Default::default()
             }))
             .with_loop(LoopMode::Alternate),
         );
@@ -280,6 +283,7 @@ pub fn SwingingCube() -> Element {
                 damping: 7.0,
                 mass: 0.5,
                 velocity: 0.0,
+                ..Default::default()
             }))
             .with_loop(LoopMode::Alternate),
         );
@@ -22,6 +22,7 @@ pub fn InteractiveCube() -> Element {
                 damping: 12.0,
                 mass: 1.0,
                 velocity: 25.0,
+                ..Default::default()
             })),
         );
 
@@ -34,6 +35,7 @@ pub fn InteractiveCube() -> Element {
                     damping: 8.0,
                     mass: 1.0,
                     velocity: 8.0,
+                    ..Default::default()
                 })),
             )
             .then(
@@ -43,6 +45,7 @@ pub fn InteractiveCube() -> Element {
                     damping: 15.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             );
 
@@ -55,6 +58,7 @@ pub fn InteractiveCube() -> Element {
                     damping: 5.0,
                     mass: 0.5,
                     velocity: 10.0,
+                    ..Default::default()
                 })),
             )
             .then(
@@ -64,6 +68,7 @@ pub fn InteractiveCube() -> Element {
                     damping: 10.0,
                     mass: 0.5,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             );
 
@@ -79,6 +84,7 @@ pub fn InteractiveCube() -> Element {
                 damping: 10.0,
                 mass: 0.5,
                 velocity: 5.0,
+                ..Default::default()
             })),
         );
 
@@ -103,6 +109,7 @@ pub fn InteractiveCube() -> Element {
                 damping: 15.0,
                 mass: 0.8,
                 velocity: 0.0,
+                ..Default::default()
             })),
         );
 
@@ -113,6 +120,7 @@ pub fn InteractiveCube() -> Element {
                 damping: 15.0,
                 mass: 0.8,
                 velocity: 0.0,
+                ..Default::default()
             })),
         );
     };
@@ -125,6 +133,7 @@ pub fn InteractiveCube() -> Element {
                 damping: 15.0,
                 mass: 0.8,
                 velocity: 0.0,
+                ..Default::default()
             })),
         );
     };
@@ -137,6 +146,7 @@ pub fn InteractiveCube() -> Element {
                 damping: 15.0,
                 mass: 0.8,
                 velocity: 0.0,
+                ..Default::default()
             })),
         );
 
@@ -148,6 +158,7 @@ pub fn InteractiveCube() -> Element {
                 damping: 15.0,
                 mass: 0.8,
                 velocity: 0.0,
+                ..Default::default()
             })),
         );
 
@@ -158,6 +169,7 @@ pub fn InteractiveCube() -> Element {
                 damping: 15.0,
                 mass: 0.8,
                 velocity: 0.0,
+                ..Default::default()
             })),
         );
     };
@@ -82,6 +82,7 @@ pub fn MorphingShape(shapes: Vec<&'static str>, duration: f32) -> Element {
                 damping: 5.0,    // Lower damping for organic movement
                 mass: 0.6,       // Lighter mass for faster response
                 velocity: 0.8,   // Increased initial velocity
+                ..Default::default()
             }))
             .with_loop(LoopMode::Infinite),
         );
@@ -94,6 +95,7 @@ pub fn MorphingShape(shapes: Vec<&'static str>, duration: f32) -> Element {
                 damping: 3.0,
                 mass: 0.5,
                 velocity: 0.5,
+                ..Default::default()
             }))
             .with_loop(LoopMode::Infinite),
         );
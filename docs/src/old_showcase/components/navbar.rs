@@ -14,6 +14,7 @@ pub fn Navbar() -> Element {
                 damping: 20.0,
                 mass: 1.0,
                 velocity: 10.0,
+                ..Default::default()
             })),
         );
     });
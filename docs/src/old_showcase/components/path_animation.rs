@@ -9,10 +9,10 @@ pub fn PathAnimation(path: &'static str, duration: f32) -> Element {
     use_effect(move || {
         dash_offset.animate_to(
             0.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_secs_f32(duration),
-                easing: easer::functions::Cubic::ease_in_out,
-            }))
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_secs_f32(duration))
+                    .with_easing(easer::functions::Cubic::ease_in_out),
+            ))
             .with_loop(LoopMode::Infinite),
         );
     });
@@ -9,10 +9,9 @@ pub fn ProgressBar(title: &'static str) -> Element {
     use_effect(move || {
         progress.animate_to(
             100.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_secs(5),
-                easing: easer::functions::Sine::ease_in_out,
-            }))
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_secs(5)).with_easing(easer::functions::Sine::ease_in_out),
+            ))
             .with_loop(LoopMode::Infinite),
         );
     });
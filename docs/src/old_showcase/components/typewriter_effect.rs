@@ -9,23 +9,23 @@ pub fn TypewriterEffect(text: &'static str) -> Element {
     let text_len = text.len() as f32;
 
     use_effect(move || {
-        // Start typing animation
+        // Start typing animation, 0.1s per character
         char_count.animate_to(
             text_len,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_secs_f32(text_len * 0.1), // 0.1s per character
-                easing: easer::functions::Linear::ease_in_out,
-            }))
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_secs_f32(text_len * 0.1))
+                    .with_easing(easer::functions::Linear::ease_in_out),
+            ))
             .with_loop(LoopMode::Infinite),
         );
 
         // Start cursor blink
         cursor_opacity.animate_to(
             0.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_secs(1),
-                easing: easer::functions::Linear::ease_in_out,
-            }))
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_secs(1))
+                    .with_easing(easer::functions::Linear::ease_in_out),
+            ))
             .with_loop(LoopMode::Infinite),
         );
     });
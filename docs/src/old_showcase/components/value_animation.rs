@@ -9,20 +9,19 @@ pub fn ValueAnimationShowcase() -> Element {
     let start_animation = move |_| {
         value.animate_to(
             100.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_secs(10),
-                easing: easer::functions::Sine::ease_in_out,
-            })),
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_secs(10))
+                    .with_easing(easer::functions::Sine::ease_in_out),
+            )),
         );
     };
 
     let reset_animation = move |_| {
         value.animate_to(
             0.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_secs(3),
-                easing: easer::functions::Sine::ease_out,
-            })),
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_secs(3)).with_easing(easer::functions::Sine::ease_out),
+            )),
         );
     };
 
@@ -57,10 +57,10 @@ fn StepOne() -> Element {
     let animate = move |_| {
         value.animate_to(
             100.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_millis(1000),
-                easing: easer::functions::Linear::ease_in_out,
-            })),
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_millis(1000))
+                    .with_easing(easer::functions::Linear::ease_in_out),
+            )),
         );
     };
 
@@ -97,10 +97,10 @@ let mut value = use_motion(0.0f32);
 // 3. Animate the value
 value.animate_to(
     100.0,
-    AnimationConfig::new(AnimationMode::Tween(Tween {
-        duration: Duration::from_millis(1000),
-        easing: easer::functions::Linear::ease_in_out,
-    })),
+    AnimationConfig::new(AnimationMode::Tween(
+        Tween::new(Duration::from_millis(1000))
+            .with_easing(easer::functions::Linear::ease_in_out),
+    )),
 );"#.to_string(),
                             language: "rust".to_string(),
                         }
@@ -177,10 +177,10 @@ fn StepTwo() -> Element {
     let animate_tween = move |_| {
         tween_value.animate_to(
             100.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_millis(1000),
-                easing: easer::functions::Cubic::ease_in_out,
-            })),
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_millis(1000))
+                    .with_easing(easer::functions::Cubic::ease_in_out),
+            )),
         );
     };
 
@@ -192,6 +192,7 @@ fn StepTwo() -> Element {
                 damping: 10.0,
                 mass: 1.0,
                 velocity: 0.0,
+                ..Default::default()
             })),
         );
     };
@@ -252,7 +253,7 @@ fn StepTwo() -> Element {
                     // Code snippet
                     div { class: "bg-dark-200/50 p-2 rounded-lg text-xs mb-3",
                         code { class: "text-primary/90",
-                            "AnimationMode::Tween(Tween {{\n  duration: Duration::from_millis(1000),\n  easing: easer::functions::Cubic::ease_in_out\n}})"
+                            "AnimationMode::Tween(\n  Tween::new(Duration::from_millis(1000))\n    .with_easing(easer::functions::Cubic::ease_in_out)\n)"
                         }
                     }
 
@@ -259,6 +259,7 @@ fn StepTwo() -> Element {
                 damping: 10.0,
                 mass: 1.0,
                 velocity: 0.0,
+                ..Default::default()
             })),
         );
     };
@@ -293,6 +294,7 @@ petal.animate_to(
         damping: 10.0,
         mass: 1.0,
         velocity: 0.0,
+        ..Default::default()
     })),
 );"#.to_string(),
                         language: "rust".to_string(),
@@ -355,6 +357,7 @@ fn StepThree() -> Element {
                     damping: 10.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             )
             .then(
@@ -364,6 +367,7 @@ fn StepThree() -> Element {
                     damping: 12.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             )
             .then(
@@ -373,6 +377,7 @@ fn StepThree() -> Element {
                     damping: 15.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             );
 
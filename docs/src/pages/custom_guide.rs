@@ -174,6 +174,7 @@ fn StepTwo() -> Element {
                 damping: 10.0,
                 mass: 1.0,
                 velocity: 0.0,
+                ..Default::default()
             })),
         );
     };
@@ -208,6 +209,7 @@ petal.animate_to(
         damping: 10.0,
         mass: 1.0,
         velocity: 0.0,
+        ..Default::default()
     })),
 );"#.to_string(),
                         language: "rust".to_string(),
@@ -270,6 +272,7 @@ fn StepThree() -> Element {
                     damping: 10.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             )
             .then(
@@ -279,6 +282,7 @@ fn StepThree() -> Element {
                     damping: 12.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             )
             .then(
@@ -288,6 +292,7 @@ fn StepThree() -> Element {
                     damping: 15.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             );
 
@@ -40,6 +40,7 @@ pub fn Home() -> Element {
                     damping: 10.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 }))
                 .with_loop(LoopMode::Infinite),
             );
@@ -229,6 +230,7 @@ fn FeatureCard(title: &'static str, description: &'static str, icon: &'static st
                                 damping: 20.0,
                                 mass: 1.0,
                                 velocity: 0.0,
+                                ..Default::default()
                             }),
                         ),
                     );
@@ -241,6 +243,7 @@ fn FeatureCard(title: &'static str, description: &'static str, icon: &'static st
                                 damping: 20.0,
                                 mass: 1.0,
                                 velocity: 0.0,
+                                ..Default::default()
                             }),
                         ),
                     );
@@ -255,6 +258,7 @@ fn FeatureCard(title: &'static str, description: &'static str, icon: &'static st
                                 damping: 20.0,
                                 mass: 1.0,
                                 velocity: 0.0,
+                                ..Default::default()
                             }),
                         ),
                     );
@@ -267,6 +271,7 @@ fn FeatureCard(title: &'static str, description: &'static str, icon: &'static st
                                 damping: 20.0,
                                 mass: 1.0,
                                 velocity: 0.0,
+                                ..Default::default()
                             }),
                         ),
                     );
@@ -75,10 +75,10 @@ fn StepOne() -> Element {
     let start_infinite = move |_| {
         infinite_value.animate_to(
             100.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_millis(1000),
-                easing: easer::functions::Cubic::ease_in_out,
-            }))
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_millis(1000))
+                    .with_easing(easer::functions::Cubic::ease_in_out),
+            ))
             .with_loop(LoopMode::Infinite),
         );
     };
@@ -86,10 +86,10 @@ fn StepOne() -> Element {
     let start_times = move |_| {
         times_value.animate_to(
             100.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_millis(1000),
-                easing: easer::functions::Cubic::ease_in_out,
-            }))
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_millis(1000))
+                    .with_easing(easer::functions::Cubic::ease_in_out),
+            ))
             .with_loop(LoopMode::Times(3)),
         );
     };
@@ -97,10 +97,10 @@ fn StepOne() -> Element {
     let start_alternate = move |_| {
         alternate_value.animate_to(
             100.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_millis(1000),
-                easing: easer::functions::Cubic::ease_in_out,
-            }))
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_millis(1000))
+                    .with_easing(easer::functions::Cubic::ease_in_out),
+            ))
             .with_loop(LoopMode::Alternate),
         );
     };
@@ -108,10 +108,10 @@ fn StepOne() -> Element {
     let start_alternate_times = move |_| {
         alternate_times_value.animate_to(
             100.0,
-            AnimationConfig::new(AnimationMode::Tween(Tween {
-                duration: Duration::from_millis(1000),
-                easing: easer::functions::Cubic::ease_in_out,
-            }))
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new(Duration::from_millis(1000))
+                    .with_easing(easer::functions::Cubic::ease_in_out),
+            ))
             .with_loop(LoopMode::AlternateTimes(3)),
         );
     };
@@ -271,6 +271,7 @@ fn StepTwo() -> Element {
                 damping: 10.0,
                 mass: 1.0,
                 velocity: 0.0,
+                ..Default::default()
             }))
             .with_delay(Duration::from_millis(1000)),
         );
@@ -347,6 +348,7 @@ fn StepThree() -> Element {
                     damping: 10.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             )
             .then(
@@ -356,6 +358,7 @@ fn StepThree() -> Element {
                     damping: 15.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             )
             .then(
@@ -365,6 +368,7 @@ fn StepThree() -> Element {
                     damping: 20.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             );
 
@@ -498,6 +502,7 @@ fn StepFour() -> Element {
                     damping: 10.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             )
             .then(
@@ -507,6 +512,7 @@ fn StepFour() -> Element {
                     damping: 15.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             )
             .then(
@@ -516,6 +522,7 @@ fn StepFour() -> Element {
                     damping: 20.0,
                     mass: 1.0,
                     velocity: 0.0,
+                    ..Default::default()
                 })),
             );
 
@@ -556,6 +563,7 @@ fn StepFour() -> Element {
             damping: 20.0,
             mass: 1.0,
             velocity: 0.0,
+            ..Default::default()
         }));
 
         // Initial color
@@ -573,6 +581,7 @@ fn StepFour() -> Element {
             damping: 20.0,
             mass: 1.0,
             velocity: 0.0,
+            ..Default::default()
         }));
 
         // Reset keyframe transform
@@ -5,7 +5,7 @@
 
 #[cfg(test)]
 mod tests {
-    #![allow(clippy::uninlined_format_args)]
+    #![allow(clippy::uninlined_format_args, clippy::unwrap_used)]
     use instant::{Duration, Instant};
 
     /// Test web closure pooling performance
@@ -522,4 +522,92 @@ mod tests {
             motion_size
         );
     }
+
+    /// Test compiled keyframe tracks against a linear scan over every keyframe
+    #[test]
+    fn test_compiled_keyframe_lookup_performance() {
+        use crate::Motion;
+        use crate::animations::core::Animatable;
+        use crate::keyframes::KeyframeAnimation;
+
+        const KEYFRAMES: usize = 500;
+        const SAMPLES: usize = 10000;
+        const MOTIONS: usize = 50;
+        const DT: f32 = 1.0 / 60.0;
+
+        let animation = (0..KEYFRAMES)
+            .fold(
+                KeyframeAnimation::builder(Duration::from_secs(10)),
+                |builder, i| builder.keyframe((i as f32 * 0.37).sin() * 100.0),
+            )
+            .build()
+            .unwrap();
+        let track = animation.compile().unwrap();
+
+        // Baseline: find the segment by scanning adjacent pairs, as every frame used to
+        let scan = |progress: f32| {
+            animation
+                .keyframes
+                .windows(2)
+                .find(|w| progress >= w[0].offset && progress <= w[1].offset)
+                .map(|w| {
+                    let t = (progress - w[0].offset) / (w[1].offset - w[0].offset);
+                    w[0].value.interpolate(&w[1].value, t)
+                })
+        };
+
+        let scan_start = Instant::now();
+        let mut scan_sum = 0.0;
+        for i in 0..SAMPLES {
+            scan_sum += scan(i as f32 / SAMPLES as f32).unwrap_or_default();
+        }
+        let scan_time = scan_start.elapsed();
+
+        let track_start = Instant::now();
+        let mut track_sum = 0.0;
+        for i in 0..SAMPLES {
+            track_sum += track.sample(i as f32 / SAMPLES as f32).unwrap_or_default();
+        }
+        let track_time = track_start.elapsed();
+
+        println!("Linear scan over {} keyframes: {:?}", KEYFRAMES, scan_time);
+        println!("Compiled track lookup: {:?}", track_time);
+        println!(
+            "Speedup: {:.1}x",
+            scan_time.as_nanos() as f64 / track_time.as_nanos().max(1) as f64
+        );
+
+        assert!((scan_sum - track_sum).abs() < 1.0);
+        assert!(
+            track_time < scan_time,
+            "Compiled lookup ({:?}) should beat the linear scan ({:?})",
+            track_time,
+            scan_time
+        );
+
+        // Many motions sharing the same long animation
+        let mut motions: Vec<_> = (0..MOTIONS)
+            .map(|_| {
+                let mut motion = Motion::new(0.0f32);
+                motion.animate_keyframes(animation.clone());
+                motion
+            })
+            .collect();
+        let update_start = Instant::now();
+        for _ in 0..60 {
+            for motion in &mut motions {
+                motion.update(DT);
+            }
+        }
+        let update_time = update_start.elapsed();
+        println!(
+            "{} keyframe motions x 60 frames took: {:?}",
+            MOTIONS, update_time
+        );
+        assert!(
+            update_time < Duration::from_millis(100),
+            "Keyframe motion updates took too long: {:?}",
+            update_time
+        );
+    }
 }
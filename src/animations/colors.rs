@@ -131,6 +131,15 @@ impl Animatable for Color {
         (self.r * self.r + self.g * self.g + self.b * self.b + self.a * self.a).sqrt()
     }
 
+    fn clamp_between(&self, min: &Self, max: &Self) -> Self {
+        Color::new(
+            self.r.clamp_between(&min.r, &max.r),
+            self.g.clamp_between(&min.g, &max.g),
+            self.b.clamp_between(&min.b, &max.b),
+            self.a.clamp_between(&min.a, &max.a),
+        )
+    }
+
     // Uses default epsilon of 0.01 from the trait - no need for COLOR_EPSILON
 }
 
@@ -5,7 +5,7 @@
 
 use std::sync::{Arc, Mutex};
 
-use crate::animations::{spring::Spring, tween::Tween};
+use crate::animations::{decay::Decay, spring::Spring, tween::Tween};
 use instant::Duration;
 
 /// A simplified trait for types that can be animated
@@ -43,15 +43,39 @@ pub trait Animatable:
     fn epsilon() -> f32 {
         0.01 // Single default epsilon for simplicity
     }
+
+    /// Clamps each component of the value between `min` and `max`
+    ///
+    /// Used to keep decay animations within their bounds. The default implementation
+    /// returns the value unchanged, so bounds have no effect unless a type overrides it.
+    fn clamp_between(&self, min: &Self, max: &Self) -> Self {
+        let _ = (min, max);
+        *self
+    }
+}
+
+/// Turns a scalar `speed` into a typed velocity pointing from `from` towards `to`
+///
+/// Positive speeds move towards `to`, negative speeds away from it. Returns zero when the
+/// speed is zero or both values are the same.
+pub fn velocity_towards<T: Animatable>(speed: f32, from: T, to: T) -> T {
+    let delta = to - from;
+    let distance = delta.magnitude();
+    if speed == 0.0 || distance <= 0.0 {
+        return T::zero();
+    }
+    delta * (speed / distance)
 }
 
 /// Defines the type of animation to be used
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AnimationMode {
     /// Tween animation with duration and easing
     Tween(Tween),
     /// Physics-based spring animation
     Spring(Spring),
+    /// Inertia animation that decelerates from its initial velocity to a resting point
+    Decay(Decay),
 }
 
 impl Default for AnimationMode {
@@ -68,11 +92,11 @@ pub enum LoopMode {
     /// Loop animation indefinitely
     Infinite,
     /// Loop animation a specific number of times
-    Times(u8),
+    Times(u32),
     /// Loop animation back and forth indefinitely
     Alternate,
     /// Loop animation back and forth a specific number of times
-    AlternateTimes(u8),
+    AlternateTimes(u32),
 }
 
 impl Default for LoopMode {
@@ -82,6 +106,10 @@ impl Default for LoopMode {
 }
 
 pub type OnComplete = Arc<Mutex<dyn FnMut() + Send + 'static>>;
+
+/// Fastest playback rate, in either direction, that an animation advances at
+pub const MAX_PLAYBACK_RATE: f32 = 1000.0;
+
 /// Configuration for an animation
 #[derive(Clone, Default)]
 pub struct AnimationConfig {
@@ -96,6 +124,12 @@ pub struct AnimationConfig {
     /// Custom epsilon threshold for animation completion detection
     /// If None, uses the type's default epsilon from Animatable::epsilon()
     pub epsilon: Option<f32>,
+    /// Speed multiplier for the animation's own timeline
+    /// If None, plays at normal speed (1.0)
+    pub playback_rate: Option<f32>,
+    /// Whether interrupting a running animation carries its velocity into this one
+    /// If None, springs carry velocity and tweens start from rest
+    pub inherit_velocity: Option<bool>,
 }
 
 impl AnimationConfig {
@@ -107,6 +141,8 @@ impl AnimationConfig {
             delay: Duration::default(),
             on_complete: None,
             epsilon: None,
+            playback_rate: None,
+            inherit_velocity: None,
         }
     }
 
@@ -147,23 +183,129 @@ impl AnimationConfig {
         self
     }
 
-    /// Gets the total duration of the animation
+    /// Sets the playback rate of the animation
+    ///
+    /// Scales how fast the animation's timeline advances: `2.0` plays twice as fast and
+    /// `0.5` at half speed. Negative rates play tweens and keyframes backwards from their
+    /// end to their start; springs use the magnitude only. Delays always elapse first.
+    /// Rates are clamped to [`MAX_PLAYBACK_RATE`] either way, and a NaN rate plays at
+    /// normal speed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// let config = AnimationConfig::new(AnimationMode::Tween(Tween::default()))
+    ///     .with_playback_rate(-0.5); // Half speed, reversed
+    /// ```
+    pub fn with_playback_rate(mut self, rate: f32) -> Self {
+        self.playback_rate = Some(rate);
+        self
+    }
+
+    /// Gets the effective playback rate, defaulting to 1.0
+    pub fn playback_rate(&self) -> f32 {
+        match self.playback_rate {
+            Some(rate) if !rate.is_nan() => rate.clamp(-MAX_PLAYBACK_RATE, MAX_PLAYBACK_RATE),
+            _ => 1.0,
+        }
+    }
+
+    /// Sets whether interrupting a running animation carries its velocity into this one
+    ///
+    /// Springs carry velocity by default, so re-targeting mid-flight continues smoothly
+    /// instead of stopping and restarting. Tweens start from rest by default; when enabled
+    /// they blend out of the inherited velocity so the motion stays C1-continuous.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// let config = AnimationConfig::new(AnimationMode::Tween(Tween::default()))
+    ///     .with_inherit_velocity(true);
+    /// ```
+    pub fn with_inherit_velocity(mut self, inherit: bool) -> Self {
+        self.inherit_velocity = Some(inherit);
+        self
+    }
+
+    /// Gets whether this animation carries the velocity of an interrupted one
+    pub fn inherits_velocity(&self) -> bool {
+        self.inherit_velocity.unwrap_or(matches!(
+            self.mode,
+            AnimationMode::Spring(_) | AnimationMode::Decay(_)
+        ))
+    }
+
+    /// Gets the total duration of the animation, excluding the delay
+    ///
+    /// Infinite loops return [`Duration::MAX`]; use [`AnimationConfig::total_duration`] to
+    /// handle them explicitly. Springs are estimated for a travel distance of 1.0.
     pub fn get_duration(&self) -> Duration {
-        match &self.mode {
-            AnimationMode::Spring(_) => {
-                // Springs don't have a fixed duration, estimate based on typical settling time
-                Duration::from_secs_f32(1.0) // You might want to adjust this based on spring parameters
-            }
-            AnimationMode::Tween(tween) => {
-                let base_duration = tween.duration;
-                match self.loop_mode {
-                    Some(LoopMode::Infinite) => Duration::from_secs(f32::INFINITY as u64),
-                    Some(LoopMode::Times(count)) => base_duration * count.into(),
-                    Some(LoopMode::Alternate) => Duration::from_secs(f32::INFINITY as u64),
-                    Some(LoopMode::AlternateTimes(count)) => base_duration * (count * 2).into(),
-                    Some(LoopMode::None) | None => base_duration,
-                }
-            }
+        self.total_duration().unwrap_or(Duration::MAX)
+    }
+
+    /// Gets the total duration of the animation, excluding the delay
+    ///
+    /// Returns `None` for infinite loops. Springs are estimated for a travel distance of 1.0;
+    /// use [`AnimationConfig::total_duration_for`] when the distance is known.
+    pub fn total_duration(&self) -> Option<Duration> {
+        self.total_duration_for(1.0)
+    }
+
+    /// Gets the total duration of the animation when it travels `distance`, excluding the delay
+    ///
+    /// Spring durations are the settling time computed from stiffness, damping, mass,
+    /// initial velocity and epsilon. Decays ignore `distance` and settle from
+    /// [`Decay::velocity`]. Loops multiply the duration, saturating at
+    /// [`Duration::MAX`], and playback rates divide it. Returns `None` for infinite loops
+    /// and for a playback rate of zero, which never finishes.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// let config = AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+    /// let settle = config.total_duration_for(100.0).unwrap();
+    /// assert!(settle > Duration::from_secs(1) && settle < Duration::from_secs(3));
+    ///
+    /// let forever = config.with_loop(LoopMode::Infinite);
+    /// assert_eq!(forever.total_duration(), None);
+    /// ```
+    pub fn total_duration_for(&self, distance: f32) -> Option<Duration> {
+        let iteration = match &self.mode {
+            AnimationMode::Spring(spring) => spring.settling_time(
+                -distance.abs(),
+                spring.velocity,
+                self.epsilon.unwrap_or_else(<f32 as Animatable>::epsilon),
+            ),
+            AnimationMode::Tween(tween) => tween.duration,
+            AnimationMode::Decay(decay) => decay.settling_time(
+                decay.velocity,
+                self.epsilon.unwrap_or_else(<f32 as Animatable>::epsilon),
+            ),
+        };
+
+        let rate = self.playback_rate().abs();
+        if rate == 0.0 {
+            // A stopped timeline never reaches the end
+            return None;
+        }
+        let iteration = if rate != 1.0 {
+            // Tiny rates stretch the iteration past what a `Duration` holds
+            Duration::try_from_secs_f64(iteration.as_secs_f64() / f64::from(rate))
+                .unwrap_or(Duration::MAX)
+        } else {
+            iteration
+        };
+
+        match self.loop_mode.unwrap_or(LoopMode::None) {
+            LoopMode::Infinite | LoopMode::Alternate => None,
+            LoopMode::Times(count) => Some(iteration.checked_mul(count).unwrap_or(Duration::MAX)),
+            LoopMode::AlternateTimes(count) => Some(
+                iteration
+                    .checked_mul(count)
+                    .and_then(|duration| duration.checked_mul(2))
+                    .unwrap_or(Duration::MAX),
+            ),
+            LoopMode::None => Some(iteration),
         }
     }
 
@@ -176,3 +318,75 @@ impl AnimationConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_tween_duration_with_loops() {
+        let tween =
+            AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(200))));
+        assert_eq!(tween.total_duration(), Some(Duration::from_millis(200)));
+
+        let times = tween.clone().with_loop(LoopMode::Times(3));
+        assert_eq!(times.total_duration(), Some(Duration::from_millis(600)));
+
+        let alternate = tween.clone().with_loop(LoopMode::AlternateTimes(200));
+        assert_eq!(alternate.total_duration(), Some(Duration::from_secs(80)));
+
+        // Counts past 255 and overflowing products are fine
+        let many = tween.clone().with_loop(LoopMode::Times(1000));
+        assert_eq!(many.total_duration(), Some(Duration::from_secs(200)));
+        let slow = AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::MAX / 2)))
+            .with_loop(LoopMode::AlternateTimes(u32::MAX));
+        assert_eq!(slow.total_duration(), Some(Duration::MAX));
+        let crawling = tween.clone().with_playback_rate(1e-30);
+        assert_eq!(crawling.total_duration(), Some(Duration::MAX));
+        let stopped = tween.clone().with_playback_rate(0.0);
+        assert_eq!(stopped.total_duration(), None);
+        let racing = tween.clone().with_playback_rate(f32::INFINITY);
+        assert_eq!(racing.total_duration(), Some(Duration::from_micros(200)));
+
+        let infinite = tween.clone().with_loop(LoopMode::Infinite);
+        assert_eq!(infinite.total_duration(), None);
+        assert_eq!(infinite.get_duration(), Duration::MAX);
+        assert_eq!(tween.with_loop(LoopMode::Alternate).total_duration(), None);
+    }
+
+    #[test]
+    fn test_spring_duration_uses_settling_time() {
+        let spring = Spring::default();
+        let config = AnimationConfig::new(AnimationMode::Spring(spring));
+
+        let far = config.total_duration_for(100.0).unwrap();
+        assert_eq!(far, spring.settling_time(-100.0, 0.0, 0.01));
+        assert!(far > config.total_duration().unwrap());
+
+        // A looser epsilon settles sooner, a faster playback rate finishes sooner
+        let loose = config
+            .clone()
+            .with_epsilon(0.1)
+            .total_duration_for(100.0)
+            .unwrap();
+        assert!(loose < far);
+        let fast = config
+            .with_playback_rate(2.0)
+            .total_duration_for(100.0)
+            .unwrap();
+        assert_eq!(fast, far / 2);
+    }
+
+    #[test]
+    fn test_decay_duration_uses_its_own_velocity() {
+        let decay = Decay::default().with_velocity(1000.0);
+        let config = AnimationConfig::new(AnimationMode::Decay(decay));
+        assert!(config.inherits_velocity());
+        assert_eq!(
+            config.total_duration_for(5.0),
+            Some(decay.settling_time(1000.0, 0.01))
+        );
+        assert_eq!(config.total_duration_for(5.0), config.total_duration());
+    }
+}
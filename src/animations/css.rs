@@ -0,0 +1,539 @@
+//! Parsing of CSS easing and duration strings
+//!
+//! Lets the motion tokens shared with stylesheets drive animations directly:
+//! `"ease-in-out".parse::<Easing>()`, `parse_duration("300ms")` or
+//! `"300ms cubic-bezier(.17,.67,.83,.67)".parse::<Tween>()`.
+
+use crate::Duration;
+use crate::animations::easing::{CubicBezier, Easing, LinearStop, StepPosition};
+use crate::animations::tween::Tween;
+use std::str::FromStr;
+
+/// Errors produced while parsing CSS easing and duration strings
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum CssParseError {
+    #[error("Expected a CSS value but found an empty string")]
+    Empty,
+    #[error("Unknown easing keyword `{0}`")]
+    UnknownKeyword(String),
+    #[error("Unknown easing function `{0}()`")]
+    UnknownFunction(String),
+    #[error("Missing closing parenthesis in `{0}`")]
+    UnclosedParenthesis(String),
+    #[error("`{function}()` expects {expected} arguments but found {found}")]
+    ArgumentCount {
+        function: &'static str,
+        expected: &'static str,
+        found: usize,
+    },
+    #[error("Invalid number `{0}`")]
+    InvalidNumber(String),
+    #[error("Invalid percentage `{0}`; expected a number followed by `%`")]
+    InvalidPercentage(String),
+    #[error("`cubic-bezier()` x coordinates must be between 0 and 1 but found {0}")]
+    BezierOutOfRange(f32),
+    #[error("`steps()` needs a positive integer step count but found `{0}`")]
+    InvalidStepCount(String),
+    #[error(
+        "Unknown `steps()` position `{0}`; expected jump-start, jump-end, jump-none, jump-both, start or end"
+    )]
+    UnknownStepPosition(String),
+    #[error("`steps(n, jump-none)` needs at least 2 steps")]
+    TooFewStepsForJumpNone,
+    #[error("Invalid duration `{0}`; expected a number followed by `ms` or `s`")]
+    InvalidDuration(String),
+    #[error("Durations cannot be negative but found `{0}`")]
+    NegativeDuration(String),
+    #[error("Expected exactly one duration in `{0}`")]
+    MissingDuration(String),
+    #[error("Unexpected `{token}` in `{input}`")]
+    UnexpectedToken { token: String, input: String },
+}
+
+/// Parses a CSS `<time>` such as `"300ms"`, `"0.3s"` or `".25s"`
+///
+/// Units are required, as in CSS.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::Duration;
+/// use dioxus_motion::animations::css::parse_duration;
+/// assert_eq!(parse_duration("300ms"), Ok(Duration::from_millis(300)));
+/// assert_eq!(parse_duration(".5s"), Ok(Duration::from_millis(500)));
+/// assert!(parse_duration("300").is_err());
+/// ```
+pub fn parse_duration(input: &str) -> Result<Duration, CssParseError> {
+    let value = input.trim();
+    if value.is_empty() {
+        return Err(CssParseError::Empty);
+    }
+
+    let invalid = || CssParseError::InvalidDuration(value.to_string());
+    let lower = value.to_ascii_lowercase();
+    let (number, scale) = if let Some(number) = lower.strip_suffix("ms") {
+        (number, 0.001)
+    } else if let Some(number) = lower.strip_suffix('s') {
+        (number, 1.0)
+    } else {
+        return Err(invalid());
+    };
+
+    let number = parse_f64(number).map_err(|_| invalid())?;
+    if number < 0.0 {
+        return Err(CssParseError::NegativeDuration(value.to_string()));
+    }
+    Duration::try_from_secs_f64(number * scale).map_err(|_| invalid())
+}
+
+/// Parses CSS easing functions: keywords, `cubic-bezier()`, `steps()` and `linear()`
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+/// let easing: Easing = "cubic-bezier(.17,.67,.83,.67)".parse().unwrap();
+/// assert_eq!(easing, Easing::CubicBezier(CubicBezier::new(0.17, 0.67, 0.83, 0.67)));
+///
+/// let error = "ease-in-outt".parse::<Easing>().unwrap_err();
+/// assert_eq!(error.to_string(), "Unknown easing keyword `ease-in-outt`");
+/// ```
+impl FromStr for Easing {
+    type Err = CssParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let value = input.trim();
+        if value.is_empty() {
+            return Err(CssParseError::Empty);
+        }
+
+        let Some(open) = value.find('(') else {
+            return parse_keyword(value);
+        };
+        let Some(close) = value.rfind(')') else {
+            return Err(CssParseError::UnclosedParenthesis(value.to_string()));
+        };
+        let trailing = value[close + 1..].trim();
+        if !trailing.is_empty() || close < open {
+            return Err(CssParseError::UnexpectedToken {
+                token: if trailing.is_empty() { ")" } else { trailing }.to_string(),
+                input: value.to_string(),
+            });
+        }
+        let arguments = &value[open + 1..close];
+
+        let name = value[..open].trim().to_ascii_lowercase();
+        let arguments: Vec<&str> = arguments.split(',').map(str::trim).collect();
+        match name.as_str() {
+            "cubic-bezier" => parse_cubic_bezier(&arguments),
+            "steps" => parse_steps(&arguments),
+            "linear" => parse_linear(&arguments),
+            _ => Err(CssParseError::UnknownFunction(
+                value[..open].trim().to_string(),
+            )),
+        }
+    }
+}
+
+/// Parses a CSS transition-style pair of duration and optional easing, in either order
+///
+/// A missing easing defaults to `ease`, like CSS transitions.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+/// let tween: Tween = "300ms ease-in-out".parse().unwrap();
+/// assert_eq!(tween.duration, Duration::from_millis(300));
+/// assert_eq!(tween.easing, Easing::CubicBezier(CubicBezier::EASE_IN_OUT));
+///
+/// let tween: Tween = "steps(4, end) 1s".parse().unwrap();
+/// assert_eq!(tween.duration, Duration::from_secs(1));
+/// ```
+impl FromStr for Tween {
+    type Err = CssParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let value = input.trim();
+        if value.is_empty() {
+            return Err(CssParseError::Empty);
+        }
+
+        let mut duration = None;
+        let mut easing = None;
+        for token in split_components(value)? {
+            let starts_like_number = token
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_digit() || matches!(c, '.' | '+' | '-'));
+
+            if starts_like_number && duration.is_none() {
+                duration = Some(parse_duration(token)?);
+            } else if !starts_like_number && easing.is_none() {
+                easing = Some(token.parse::<Easing>()?);
+            } else {
+                return Err(CssParseError::UnexpectedToken {
+                    token: token.to_string(),
+                    input: value.to_string(),
+                });
+            }
+        }
+
+        let duration = duration.ok_or_else(|| CssParseError::MissingDuration(value.to_string()))?;
+        Ok(Tween::new(duration)
+            .with_curve(easing.unwrap_or(Easing::CubicBezier(CubicBezier::EASE))))
+    }
+}
+
+/// Splits on whitespace outside of parentheses
+fn split_components(value: &str) -> Result<Vec<&str>, CssParseError> {
+    let mut components = Vec::new();
+    let mut depth = 0usize;
+    let mut start = None;
+
+    for (index, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| CssParseError::UnexpectedToken {
+                        token: ")".to_string(),
+                        input: value.to_string(),
+                    })?;
+            }
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(begin) = start.take() {
+                    components.push(&value[begin..index]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        start.get_or_insert(index);
+    }
+
+    if depth > 0 {
+        return Err(CssParseError::UnclosedParenthesis(value.to_string()));
+    }
+    if let Some(begin) = start {
+        components.push(&value[begin..]);
+    }
+    Ok(components)
+}
+
+fn parse_keyword(value: &str) -> Result<Easing, CssParseError> {
+    let easing = match value.to_ascii_lowercase().as_str() {
+        "linear" => Easing::Linear,
+        "ease" => Easing::CubicBezier(CubicBezier::EASE),
+        "ease-in" => Easing::CubicBezier(CubicBezier::EASE_IN),
+        "ease-out" => Easing::CubicBezier(CubicBezier::EASE_OUT),
+        "ease-in-out" => Easing::CubicBezier(CubicBezier::EASE_IN_OUT),
+        "step-start" => Easing::steps(1, StepPosition::Start),
+        "step-end" => Easing::steps(1, StepPosition::End),
+        _ => return Err(CssParseError::UnknownKeyword(value.to_string())),
+    };
+    Ok(easing)
+}
+
+fn parse_cubic_bezier(arguments: &[&str]) -> Result<Easing, CssParseError> {
+    let [x1, y1, x2, y2] = arguments else {
+        return Err(CssParseError::ArgumentCount {
+            function: "cubic-bezier",
+            expected: "4",
+            found: arguments.len(),
+        });
+    };
+
+    let (x1, y1, x2, y2) = (
+        parse_number(x1)?,
+        parse_number(y1)?,
+        parse_number(x2)?,
+        parse_number(y2)?,
+    );
+    for x in [x1, x2] {
+        if !(0.0..=1.0).contains(&x) {
+            return Err(CssParseError::BezierOutOfRange(x));
+        }
+    }
+    Ok(Easing::CubicBezier(CubicBezier::new(x1, y1, x2, y2)))
+}
+
+fn parse_steps(arguments: &[&str]) -> Result<Easing, CssParseError> {
+    let (count, position) = match arguments {
+        [count] => (count, None),
+        [count, position] => (count, Some(position)),
+        _ => {
+            return Err(CssParseError::ArgumentCount {
+                function: "steps",
+                expected: "1 or 2",
+                found: arguments.len(),
+            });
+        }
+    };
+
+    let count = count
+        .parse::<u32>()
+        .ok()
+        .filter(|count| *count > 0)
+        .ok_or_else(|| CssParseError::InvalidStepCount(count.to_string()))?;
+
+    let position = match position.map(|p| p.to_ascii_lowercase()).as_deref() {
+        None | Some("jump-end" | "end") => StepPosition::End,
+        Some("jump-start" | "start") => StepPosition::Start,
+        Some("jump-none") => StepPosition::None,
+        Some("jump-both") => StepPosition::Both,
+        Some(_) => {
+            return Err(CssParseError::UnknownStepPosition(
+                position.map(|p| p.to_string()).unwrap_or_default(),
+            ));
+        }
+    };
+    if position == StepPosition::None && count < 2 {
+        return Err(CssParseError::TooFewStepsForJumpNone);
+    }
+    Ok(Easing::steps(count, position))
+}
+
+fn parse_linear(arguments: &[&str]) -> Result<Easing, CssParseError> {
+    if arguments.iter().all(|argument| argument.is_empty()) {
+        return Err(CssParseError::ArgumentCount {
+            function: "linear",
+            expected: "1 or more",
+            found: 0,
+        });
+    }
+
+    let mut stops = Vec::with_capacity(arguments.len());
+    for argument in arguments {
+        let mut parts = argument.split_whitespace();
+        let output = parse_number(parts.next().unwrap_or_default())?;
+        let inputs: Vec<&str> = parts.collect();
+
+        match inputs.as_slice() {
+            [] => stops.push(LinearStop::new(output)),
+            // One or two percentages; two place the same output at both inputs
+            [first, rest @ ..] if rest.len() <= 1 => {
+                stops.push(LinearStop::new(output).at(parse_percentage(first)?));
+                if let Some(second) = rest.first() {
+                    stops.push(LinearStop::new(output).at(parse_percentage(second)?));
+                }
+            }
+            _ => {
+                return Err(CssParseError::UnexpectedToken {
+                    token: inputs[2..].join(" "),
+                    input: argument.to_string(),
+                });
+            }
+        }
+    }
+    Ok(Easing::linear_stops(stops))
+}
+
+/// Parses a finite CSS number such as `0.5`, `.17` or `-1`
+fn parse_number(value: &str) -> Result<f32, CssParseError> {
+    let number = parse_f64(value)? as f32;
+    if number.is_finite() {
+        Ok(number)
+    } else {
+        Err(CssParseError::InvalidNumber(value.trim().to_string()))
+    }
+}
+
+/// Parses a finite CSS number at full precision
+fn parse_f64(value: &str) -> Result<f64, CssParseError> {
+    let value = value.trim();
+    let looks_numeric = value
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, '.' | '+' | '-' | 'e' | 'E'));
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|number| looks_numeric && number.is_finite())
+        .ok_or_else(|| CssParseError::InvalidNumber(value.to_string()))
+}
+
+/// Parses a CSS percentage such as `75%` into a fraction
+fn parse_percentage(value: &str) -> Result<f32, CssParseError> {
+    value
+        .strip_suffix('%')
+        .and_then(|number| parse_number(number).ok())
+        .map(|percent| percent / 100.0)
+        .ok_or_else(|| CssParseError::InvalidPercentage(value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn parse(value: &str) -> Easing {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_keywords() {
+        assert_eq!(parse("linear"), Easing::Linear);
+        assert_eq!(parse("ease"), Easing::CubicBezier(CubicBezier::EASE));
+        assert_eq!(
+            parse(" Ease-In-Out "),
+            Easing::CubicBezier(CubicBezier::EASE_IN_OUT)
+        );
+        assert_eq!(parse("step-start"), Easing::steps(1, StepPosition::Start));
+        assert_eq!(parse("step-end"), Easing::steps(1, StepPosition::End));
+    }
+
+    #[test]
+    fn test_parse_functions() {
+        assert_eq!(
+            parse("cubic-bezier(.17,.67,.83,.67)"),
+            Easing::CubicBezier(CubicBezier::new(0.17, 0.67, 0.83, 0.67))
+        );
+        assert_eq!(
+            parse("cubic-bezier(0.68, -0.6, 0.32, 1.6)"),
+            Easing::CubicBezier(CubicBezier::new(0.68, -0.6, 0.32, 1.6))
+        );
+        assert_eq!(parse("steps(4, end)"), Easing::steps(4, StepPosition::End));
+        assert_eq!(parse("steps(4)"), Easing::steps(4, StepPosition::End));
+        assert_eq!(
+            parse("steps(3, jump-both)"),
+            Easing::steps(3, StepPosition::Both)
+        );
+        assert_eq!(
+            parse("steps(2,start)"),
+            Easing::steps(2, StepPosition::Start)
+        );
+    }
+
+    #[test]
+    fn test_parse_linear_stops() {
+        assert_eq!(
+            parse("linear(0, 0.25 75%, 1)"),
+            Easing::linear_stops([
+                LinearStop::new(0.0),
+                LinearStop::new(0.25).at(0.75),
+                LinearStop::new(1.0),
+            ])
+        );
+
+        // Two percentages hold the output between them
+        let hold = parse("linear(0, 0.5 25% 75%, 1)");
+        assert_eq!(hold.ease(0.5), 0.5);
+        assert!((hold.ease(0.125) - 0.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_parse_durations() {
+        assert_eq!(parse_duration("300ms"), Ok(Duration::from_millis(300)));
+        assert_eq!(parse_duration("0.3s"), Ok(Duration::from_millis(300)));
+        assert_eq!(parse_duration("2S"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_duration("0ms"), Ok(Duration::ZERO));
+
+        assert_eq!(
+            parse_duration("300"),
+            Err(CssParseError::InvalidDuration("300".into()))
+        );
+        assert_eq!(
+            parse_duration("fast"),
+            Err(CssParseError::InvalidDuration("fast".into()))
+        );
+        assert_eq!(
+            parse_duration("1e30s"),
+            Err(CssParseError::InvalidDuration("1e30s".into()))
+        );
+        assert_eq!(
+            parse_duration("-1s"),
+            Err(CssParseError::NegativeDuration("-1s".into()))
+        );
+        assert_eq!(parse_duration("  "), Err(CssParseError::Empty));
+    }
+
+    #[test]
+    fn test_parse_tween() {
+        let tween: Tween = "300ms ease-in-out".parse().unwrap();
+        assert_eq!(tween.duration, Duration::from_millis(300));
+        assert_eq!(tween.easing, Easing::CubicBezier(CubicBezier::EASE_IN_OUT));
+
+        let tween: Tween = "linear(0, 0.25 75%, 1)   1.5s".parse().unwrap();
+        assert_eq!(tween.duration, Duration::from_millis(1500));
+        assert_eq!(tween.easing, parse("linear(0, 0.25 75%, 1)"));
+
+        let tween: Tween = "200ms".parse().unwrap();
+        assert_eq!(tween.easing, Easing::CubicBezier(CubicBezier::EASE));
+
+        assert_eq!(
+            "ease".parse::<Tween>(),
+            Err(CssParseError::MissingDuration("ease".into()))
+        );
+        assert_eq!(
+            "300ms ease 100ms".parse::<Tween>(),
+            Err(CssParseError::UnexpectedToken {
+                token: "100ms".into(),
+                input: "300ms ease 100ms".into()
+            })
+        );
+    }
+
+    #[test]
+    fn test_descriptive_errors() {
+        let error = |value: &str| value.parse::<Easing>().unwrap_err();
+
+        assert_eq!(error(""), CssParseError::Empty);
+        assert_eq!(
+            error("bounce"),
+            CssParseError::UnknownKeyword("bounce".into())
+        );
+        assert_eq!(
+            error("spring(1, 2)"),
+            CssParseError::UnknownFunction("spring".into())
+        );
+        assert_eq!(
+            error("cubic-bezier(0.1, 0.2"),
+            CssParseError::UnclosedParenthesis("cubic-bezier(0.1, 0.2".into())
+        );
+        assert_eq!(
+            error("cubic-bezier(0.1, 0.2, 0.3)").to_string(),
+            "`cubic-bezier()` expects 4 arguments but found 3"
+        );
+        assert_eq!(
+            error("cubic-bezier(1.5, 0, 0.5, 1)"),
+            CssParseError::BezierOutOfRange(1.5)
+        );
+        assert_eq!(
+            error("cubic-bezier(a, 0, 0.5, 1)"),
+            CssParseError::InvalidNumber("a".into())
+        );
+        assert_eq!(
+            error("ease-in(0.5)x"),
+            CssParseError::UnexpectedToken {
+                token: "x".into(),
+                input: "ease-in(0.5)x".into()
+            }
+        );
+        assert_eq!(
+            error("steps(0)"),
+            CssParseError::InvalidStepCount("0".into())
+        );
+        assert_eq!(
+            error("steps(2.5, end)"),
+            CssParseError::InvalidStepCount("2.5".into())
+        );
+        assert_eq!(
+            error("steps(3, middle)"),
+            CssParseError::UnknownStepPosition("middle".into())
+        );
+        assert_eq!(
+            error("steps(1, jump-none)"),
+            CssParseError::TooFewStepsForJumpNone
+        );
+        assert_eq!(
+            error("linear(0, 0.5 50, 1)"),
+            CssParseError::InvalidPercentage("50".into())
+        );
+        assert_eq!(
+            error("linear()").to_string(),
+            "`linear()` expects 1 or more arguments but found 0"
+        );
+        assert_eq!(
+            error("linear(0, inf)"),
+            CssParseError::InvalidNumber("inf".into())
+        );
+    }
+}
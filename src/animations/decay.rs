@@ -0,0 +1,198 @@
+//! Decay (inertia) animations for flings
+//!
+//! Provides exponential friction like iOS scroll momentum: the value keeps moving with
+//! its initial velocity and slows down until it comes to rest on its own.
+
+use crate::Duration;
+use crate::animations::core::{self, Animatable};
+use crate::animations::spring::Spring;
+
+/// Longest time a decay is assumed to take before it is considered at rest
+const MAX_DECAY_SECS: f32 = 60.0;
+
+/// Slowest deceleration accepted, losing about a tenth of the speed per second
+const MAX_DECELERATION_RATE: f32 = 0.9999;
+
+/// Configuration for decay animations
+///
+/// The velocity shrinks by `deceleration_rate` every millisecond, so the value glides to a
+/// resting point computed from the initial velocity instead of a target. When the motion
+/// has bounds (see `Motion::animate_decay_within`), crossing one hands off to
+/// `boundary_spring`, which pulls the value back onto the bound.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+/// let decay = Decay::default();
+/// // A 1000 units/s fling comes to rest about 500 units further
+/// assert!((decay.resting_offset(1000.0f32) - 500.0).abs() < 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Decay {
+    /// Initial velocity (default: 0.0)
+    /// Speed towards the target passed to `animate_to`; use `Motion::animate_decay` for a
+    /// typed velocity
+    pub velocity: f32,
+
+    /// Fraction of the velocity kept after each millisecond (default: 0.998)
+    /// Lower values stop sooner
+    pub deceleration_rate: f32,
+
+    /// Spring that takes over when the value overshoots the motion's bounds
+    pub boundary_spring: Spring,
+}
+
+/// Default decay matching iOS "normal" scroll deceleration
+impl Default for Decay {
+    fn default() -> Self {
+        Self {
+            velocity: 0.0,
+            deceleration_rate: Self::NORMAL,
+            boundary_spring: Spring::stiff(),
+        }
+    }
+}
+
+impl Decay {
+    /// Deceleration rate of regular scroll views
+    pub const NORMAL: f32 = 0.998;
+    /// Deceleration rate of paging and picker views, which stop sooner
+    pub const FAST: f32 = 0.99;
+
+    /// Creates a decay with the given per-millisecond deceleration rate
+    pub fn new(deceleration_rate: f32) -> Self {
+        Self {
+            deceleration_rate,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the initial speed towards the target
+    pub fn with_velocity(mut self, velocity: f32) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+    /// Sets the spring used to settle on a bound after an overshoot
+    pub fn with_boundary_spring(mut self, spring: Spring) -> Self {
+        self.boundary_spring = spring;
+        self
+    }
+
+    /// Gets the exponential decay constant λ in 1/s, with `v(t) = v0·e^(−λt)`
+    ///
+    /// Rates are clamped between just above zero, which stops at once, and 0.9999, which
+    /// loses about a tenth of the speed per second. Slower rates would take hours to come
+    /// to rest.
+    pub fn decay_constant(&self) -> f32 {
+        let rate = self
+            .deceleration_rate
+            .clamp(f32::EPSILON, MAX_DECELERATION_RATE);
+        -rate.ln() * 1000.0
+    }
+
+    /// Gets how far a value travels from `velocity` until it comes to rest
+    pub fn resting_offset<T: Animatable>(&self, velocity: T) -> T {
+        velocity * (1.0 / self.decay_constant())
+    }
+
+    /// Gets the offset travelled and the velocity left `t` seconds after starting with `velocity`
+    pub fn offset_at<T: Animatable>(&self, velocity: T, t: f32) -> (T, T) {
+        let lambda = self.decay_constant();
+        let remaining = (-lambda * t.max(0.0)).exp();
+        (
+            velocity * ((1.0 - remaining) / lambda),
+            velocity * remaining,
+        )
+    }
+
+    /// Gets the time until a value started with `speed` is within `epsilon` of its resting point
+    ///
+    /// Capped at 60 seconds.
+    pub fn settling_time(&self, speed: f32, epsilon: f32) -> Duration {
+        let lambda = self.decay_constant();
+        let distance = speed.abs() / lambda;
+        if distance <= epsilon.max(0.0) {
+            return Duration::ZERO;
+        }
+        let secs = (distance / epsilon.max(f32::EPSILON)).ln() / lambda;
+        Duration::from_secs_f32(secs.clamp(0.0, MAX_DECAY_SECS))
+    }
+
+    /// Gets [`Decay::velocity`] as a typed velocity pointing from `from` towards `to`
+    pub fn velocity_towards<T: Animatable>(&self, from: T, to: T) -> T {
+        core::velocity_towards(self.velocity, from, to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decay_offset_approaches_resting_point() {
+        let decay = Decay::default();
+        let rest = decay.resting_offset(1000.0f32);
+
+        let (offset, velocity) = decay.offset_at(1000.0f32, 0.0);
+        assert_eq!(offset, 0.0);
+        assert_eq!(velocity, 1000.0);
+
+        let mut previous = 0.0;
+        for step in 1..=50 {
+            let (offset, velocity) = decay.offset_at(1000.0f32, step as f32 * 0.1);
+            assert!(offset > previous && offset < rest);
+            assert!(velocity > 0.0 && velocity < 1000.0);
+            previous = offset;
+        }
+    }
+
+    #[test]
+    fn test_decay_velocity_is_derivative_of_offset() {
+        let decay = Decay::new(Decay::FAST);
+        let h = 1e-3;
+        let (before, _) = decay.offset_at(600.0f32, 0.2 - h);
+        let (after, _) = decay.offset_at(600.0f32, 0.2 + h);
+        let (_, velocity) = decay.offset_at(600.0f32, 0.2);
+        assert!(((after - before) / (2.0 * h) - velocity).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_slow_deceleration_is_clamped() {
+        let slowest = Decay::new(MAX_DECELERATION_RATE);
+        for rate in [1.0, 1.5, 0.99999] {
+            assert_eq!(Decay::new(rate).decay_constant(), slowest.decay_constant());
+        }
+        let rest = Decay::new(1.0).resting_offset(1000.0f32);
+        assert!((rest - 10_000.0).abs() < 10.0, "{rest}");
+    }
+
+    #[test]
+    fn test_faster_deceleration_stops_sooner() {
+        let normal = Decay::default();
+        let fast = Decay::new(Decay::FAST);
+        assert!(fast.resting_offset(1000.0f32) < normal.resting_offset(1000.0f32));
+        assert!(fast.settling_time(1000.0, 0.01) < normal.settling_time(1000.0, 0.01));
+    }
+
+    #[test]
+    fn test_decay_settling_time_reaches_epsilon() {
+        let decay = Decay::default();
+        let settle = decay.settling_time(1000.0, 0.01).as_secs_f32();
+        let rest = decay.resting_offset(1000.0f32);
+
+        let (offset, _) = decay.offset_at(1000.0f32, settle);
+        assert!((rest - offset).abs() <= 0.0101);
+        let (offset, _) = decay.offset_at(1000.0f32, settle * 0.9);
+        assert!((rest - offset).abs() > 0.01);
+
+        assert_eq!(decay.settling_time(0.0, 0.01), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_decay_velocity_towards() {
+        let decay = Decay::default().with_velocity(300.0);
+        assert_eq!(decay.velocity_towards(10.0f32, 0.0), -300.0);
+        assert_eq!(Decay::default().velocity_towards(0.0f32, 10.0), 0.0);
+    }
+}
@@ -0,0 +1,1158 @@
+//! Easing curves for tweens and keyframes
+//!
+//! Puts `easer` curves, parameterized back/elastic curves, CSS timing functions
+//! (`cubic-bezier()`, `steps()`, `linear()`), normalized springs and user closures behind
+//! one [`Easing`] type, which can be exported back to CSS.
+
+use crate::Duration;
+use crate::animations::spring::Spring;
+use easer::functions::Easing as _;
+use std::f32::consts::TAU;
+use std::sync::Arc;
+
+/// Easing function in the `easer` signature: `(t, begin, change, duration)`
+pub type EasingFn = fn(f32, f32, f32, f32) -> f32;
+
+/// Tolerance of the bezier solver on the time axis, finer than any browser uses
+const BEZIER_EPSILON: f64 = 1e-7;
+/// Newton-Raphson iterations before falling back to bisection
+const NEWTON_ITERATIONS: usize = 8;
+/// Slopes below this make Newton-Raphson steps unreliable
+const NEWTON_MIN_SLOPE: f64 = 1e-6;
+
+/// CSS `cubic-bezier(x1, y1, x2, y2)` timing function
+///
+/// The curve runs from `(0, 0)` to `(1, 1)` with control points `(x1, y1)` and `(x2, y2)`.
+/// Solving for the curve parameter uses Newton-Raphson with a bisection fallback, like
+/// browsers do, so values match CSS transitions. `y` may leave `[0, 1]` to overshoot.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+/// let emphasized = CubicBezier::new(0.2, 0.0, 0.0, 1.0);
+/// assert!(emphasized.ease(0.5) > 0.85);
+/// assert!((CubicBezier::EASE.ease(0.5) - 0.8024).abs() < 1e-4);
+///
+/// let tween = Tween::new(Duration::from_millis(300)).with_curve(emphasized);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier {
+    pub x1: f32,
+    pub y1: f32,
+    pub x2: f32,
+    pub y2: f32,
+}
+
+impl CubicBezier {
+    /// CSS `linear`
+    pub const LINEAR: Self = Self::new_unchecked(0.0, 0.0, 1.0, 1.0);
+    /// CSS `ease`
+    pub const EASE: Self = Self::new_unchecked(0.25, 0.1, 0.25, 1.0);
+    /// CSS `ease-in`
+    pub const EASE_IN: Self = Self::new_unchecked(0.42, 0.0, 1.0, 1.0);
+    /// CSS `ease-out`
+    pub const EASE_OUT: Self = Self::new_unchecked(0.0, 0.0, 0.58, 1.0);
+    /// CSS `ease-in-out`
+    pub const EASE_IN_OUT: Self = Self::new_unchecked(0.42, 0.0, 0.58, 1.0);
+
+    /// Creates a curve from its two control points
+    ///
+    /// `x1` and `x2` are clamped to `[0, 1]` so the curve stays a function of time, as CSS
+    /// requires.
+    pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self::new_unchecked(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2)
+    }
+
+    const fn new_unchecked(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self { x1, y1, x2, y2 }
+    }
+
+    /// Gets the eased progress for time progress `t`
+    ///
+    /// Outside `[0, 1]` the curve is extended linearly along its end tangents, like
+    /// browsers do.
+    pub fn ease(&self, t: f32) -> f32 {
+        let x = f64::from(t);
+        let curve = BezierCoefficients::new(self);
+
+        let y = if x < 0.0 {
+            x * self.start_gradient()
+        } else if x > 1.0 {
+            1.0 + (x - 1.0) * self.end_gradient()
+        } else {
+            curve.sample_y(curve.solve_x(x))
+        };
+        y as f32
+    }
+
+    /// Slope of the curve at `(0, 0)`, used to extrapolate before the start
+    fn start_gradient(&self) -> f64 {
+        if self.x1 > 0.0 {
+            f64::from(self.y1) / f64::from(self.x1)
+        } else if self.y1 == 0.0 && self.x2 > 0.0 {
+            f64::from(self.y2) / f64::from(self.x2)
+        } else {
+            0.0
+        }
+    }
+
+    /// Slope of the curve at `(1, 1)`, used to extrapolate past the end
+    fn end_gradient(&self) -> f64 {
+        if self.x2 < 1.0 {
+            (f64::from(self.y2) - 1.0) / (f64::from(self.x2) - 1.0)
+        } else if self.y2 == 1.0 && self.x1 < 1.0 {
+            (f64::from(self.y1) - 1.0) / (f64::from(self.x1) - 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Polynomial coefficients of both curve axes, `((a·s + b)·s + c)·s`
+struct BezierCoefficients {
+    ax: f64,
+    bx: f64,
+    cx: f64,
+    ay: f64,
+    by: f64,
+    cy: f64,
+}
+
+impl BezierCoefficients {
+    fn new(curve: &CubicBezier) -> Self {
+        let cx = 3.0 * f64::from(curve.x1);
+        let bx = 3.0 * (f64::from(curve.x2) - f64::from(curve.x1)) - cx;
+        let cy = 3.0 * f64::from(curve.y1);
+        let by = 3.0 * (f64::from(curve.y2) - f64::from(curve.y1)) - cy;
+        Self {
+            ax: 1.0 - cx - bx,
+            bx,
+            cx,
+            ay: 1.0 - cy - by,
+            by,
+            cy,
+        }
+    }
+
+    fn sample_x(&self, s: f64) -> f64 {
+        ((self.ax * s + self.bx) * s + self.cx) * s
+    }
+
+    fn sample_y(&self, s: f64) -> f64 {
+        ((self.ay * s + self.by) * s + self.cy) * s
+    }
+
+    fn sample_x_derivative(&self, s: f64) -> f64 {
+        (3.0 * self.ax * s + 2.0 * self.bx) * s + self.cx
+    }
+
+    /// Finds the curve parameter whose x equals `x` (within `[0, 1]`)
+    fn solve_x(&self, x: f64) -> f64 {
+        // Newton-Raphson converges in a few steps for most curves
+        let mut s = x;
+        for _ in 0..NEWTON_ITERATIONS {
+            let error = self.sample_x(s) - x;
+            if error.abs() < BEZIER_EPSILON {
+                return s;
+            }
+            let slope = self.sample_x_derivative(s);
+            if slope.abs() < NEWTON_MIN_SLOPE {
+                break;
+            }
+            s -= error / slope;
+        }
+
+        // Bisection always converges since x grows monotonically with s
+        let (mut low, mut high) = (0.0, 1.0);
+        s = x;
+        while low < high {
+            let error = self.sample_x(s) - x;
+            if error.abs() < BEZIER_EPSILON {
+                return s;
+            }
+            if error > 0.0 {
+                high = s;
+            } else {
+                low = s;
+            }
+            let next = (low + high) / 2.0;
+            if next == s {
+                break;
+            }
+            s = next;
+        }
+        s
+    }
+}
+
+/// Direction of an easing curve, matching the `ease_in`/`ease_out`/`ease_in_out` functions
+/// of `easer`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EaseKind {
+    /// Starts slowly and accelerates
+    In,
+    /// Starts quickly and decelerates
+    Out,
+    /// Accelerates, then decelerates
+    InOut,
+}
+
+/// Fixed-shape curves from the `easer` crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Curve {
+    /// Quadratic, `t²`
+    Quad,
+    /// Cubic, `t³`
+    Cubic,
+    /// Quartic, `t⁴`
+    Quart,
+    /// Quintic, `t⁵`
+    Quint,
+    /// Quarter of a sine wave
+    Sine,
+    /// Exponential, `2^(10(t - 1))`
+    Expo,
+    /// Quarter of a circle
+    Circ,
+    /// Bounces off the end like a dropped ball
+    Bounce,
+}
+
+impl Curve {
+    /// Gets the `easer` function implementing this curve
+    pub fn function(self, kind: EaseKind) -> EasingFn {
+        macro_rules! pick {
+            ($curve:ident) => {
+                match kind {
+                    EaseKind::In => easer::functions::$curve::ease_in,
+                    EaseKind::Out => easer::functions::$curve::ease_out,
+                    EaseKind::InOut => easer::functions::$curve::ease_in_out,
+                }
+            };
+        }
+        match self {
+            Self::Quad => pick!(Quad),
+            Self::Cubic => pick!(Cubic),
+            Self::Quart => pick!(Quart),
+            Self::Quint => pick!(Quint),
+            Self::Sine => pick!(Sine),
+            Self::Expo => pick!(Expo),
+            Self::Circ => pick!(Circ),
+            Self::Bounce => pick!(Bounce),
+        }
+    }
+}
+
+/// Where the jumps of a [`Easing::Steps`] curve happen, like CSS `steps(n, <position>)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum StepPosition {
+    /// Jumps at the start of each step (`jump-start`, `start`)
+    Start,
+    /// Jumps at the end of each step (`jump-end`, `end`)
+    #[default]
+    End,
+    /// Jumps at neither end, holding 0 and 1 for a step each (`jump-none`)
+    None,
+    /// Jumps at both ends (`jump-both`)
+    Both,
+}
+
+/// One stop of a CSS `linear(...)` easing: an output value and an optional input position
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearStop {
+    /// Eased progress at this stop
+    pub output: f32,
+    /// Time progress of this stop; spread evenly between neighbours when `None`
+    pub input: Option<f32>,
+}
+
+impl LinearStop {
+    /// Creates a stop whose input is spread evenly between its neighbours
+    pub fn new(output: f32) -> Self {
+        Self {
+            output,
+            input: None,
+        }
+    }
+
+    /// Places the stop at time progress `input` (0.0 to 1.0)
+    pub fn at(mut self, input: f32) -> Self {
+        self.input = Some(input);
+        self
+    }
+}
+
+impl From<f32> for LinearStop {
+    fn from(output: f32) -> Self {
+        Self::new(output)
+    }
+}
+
+/// Piecewise linear easing through resolved `(input, output)` points, like CSS `linear(...)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PiecewiseLinear {
+    points: Arc<[(f32, f32)]>,
+}
+
+impl PiecewiseLinear {
+    /// Resolves stops following the CSS rules
+    ///
+    /// The first and last stops default to inputs 0 and 1, inputs never decrease, and stops
+    /// without input are spread evenly between the surrounding known inputs. Fewer than two
+    /// stops fall back to a straight line.
+    pub fn new(stops: impl IntoIterator<Item = impl Into<LinearStop>>) -> Self {
+        let stops: Vec<LinearStop> = stops.into_iter().map(Into::into).collect();
+        if stops.len() < 2 {
+            return Self {
+                points: Arc::from([(0.0, 0.0), (1.0, 1.0)]),
+            };
+        }
+
+        let last = stops.len() - 1;
+        let mut inputs: Vec<Option<f32>> = stops.iter().map(|stop| stop.input).collect();
+        inputs[0] = inputs[0].or(Some(0.0));
+        inputs[last] = inputs[last].or(Some(1.0));
+
+        // Inputs never go backwards
+        let mut largest = f32::NEG_INFINITY;
+        for input in inputs.iter_mut().flatten() {
+            largest = largest.max(*input);
+            *input = largest;
+        }
+
+        // Spread missing inputs evenly between the known ones around them
+        let mut previous = 0;
+        for index in 1..=last {
+            if let Some(end) = inputs[index] {
+                let start = inputs[previous].unwrap_or(end);
+                let gap = (index - previous) as f32;
+                for (offset, input) in inputs[previous + 1..index].iter_mut().enumerate() {
+                    *input = Some(start + (end - start) * (offset + 1) as f32 / gap);
+                }
+                previous = index;
+            }
+        }
+
+        let points = stops
+            .iter()
+            .zip(inputs)
+            .map(|(stop, input)| (input.unwrap_or(0.0), stop.output))
+            .collect();
+        Self { points }
+    }
+
+    /// Gets the resolved `(input, output)` points
+    pub fn points(&self) -> &[(f32, f32)] {
+        &self.points
+    }
+
+    /// Gets the CSS `linear(...)` function for this curve
+    ///
+    /// Inputs are left out when the points are evenly spaced from 0 to 1.
+    pub fn to_css(&self) -> String {
+        let last = self.points.len().saturating_sub(1).max(1) as f32;
+        let evenly_spaced = self
+            .points
+            .iter()
+            .enumerate()
+            .all(|(index, (input, _))| (input - index as f32 / last).abs() < 1e-5);
+
+        let stops: Vec<String> = self
+            .points
+            .iter()
+            .map(|(input, output)| {
+                if evenly_spaced {
+                    css_number(*output)
+                } else {
+                    format!("{} {}%", css_number(*output), css_number(input * 100.0))
+                }
+            })
+            .collect();
+        format!("linear({})", stops.join(", "))
+    }
+
+    /// Gets the eased progress for time progress `t`
+    ///
+    /// Outside the first and last stop the end segments are extended.
+    pub fn ease(&self, t: f32) -> f32 {
+        let points = &self.points;
+        let len = points.len();
+        if len < 2 {
+            return t;
+        }
+
+        // The segment whose end lies after `t`, falling back to the end segments
+        let end = points
+            .iter()
+            .skip(1)
+            .position(|(input, _)| *input > t)
+            .map_or(len - 1, |index| index + 1);
+        let ((x0, y0), (x1, y1)) = (points[end - 1], points[end]);
+
+        if x1 <= x0 {
+            return y1;
+        }
+        y0 + (y1 - y0) * (t - x0) / (x1 - x0)
+    }
+}
+
+/// User-supplied easing closure mapping time progress to eased progress
+///
+/// Two custom easings are equal only when they share the same closure.
+#[derive(Clone)]
+pub struct CustomEasing(Arc<dyn Fn(f32) -> f32 + Send + Sync>);
+
+impl CustomEasing {
+    /// Wraps a closure
+    pub fn new(function: impl Fn(f32) -> f32 + Send + Sync + 'static) -> Self {
+        Self(Arc::new(function))
+    }
+
+    /// Gets the eased progress for time progress `t`
+    pub fn ease(&self, t: f32) -> f32 {
+        (self.0)(t)
+    }
+}
+
+impl std::fmt::Debug for CustomEasing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CustomEasing({:p})", Arc::as_ptr(&self.0).cast::<()>())
+    }
+}
+
+impl PartialEq for CustomEasing {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Spring motion from 0 to 1, normalized to the spring's settling time
+///
+/// Lets a [`Tween`](crate::animations::tween::Tween) with a fixed duration keep the
+/// character of a [`Spring`]: the curve is the exact spring solution, including any
+/// overshoot and [`Spring::velocity`], stretched or squeezed to the tween's duration.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+/// let easing = Spring::bouncy().easing();
+/// let tween = Tween::new(Duration::from_millis(400)).with_curve(easing);
+///
+/// // Or keep the spring's own duration
+/// let natural = Tween::from_spring(Spring::bouncy());
+/// assert_eq!(natural.duration, easing.duration());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringEasing {
+    spring: Spring,
+    settling_secs: f32,
+    /// Initial velocity in distances per second
+    velocity: f32,
+}
+
+impl SpringEasing {
+    /// Fraction of the distance within which the spring counts as settled
+    pub const PRECISION: f32 = 0.001;
+
+    /// Creates the normalized curve of `spring`, settled within [`Self::PRECISION`]
+    pub fn new(spring: Spring) -> Self {
+        Self::for_distance(spring, 1.0, Self::PRECISION)
+    }
+
+    /// Creates the normalized curve of `spring` travelling `distance`, settled within `epsilon`
+    ///
+    /// Longer distances take longer to settle within the same absolute `epsilon`, and
+    /// [`Spring::velocity`] is relative to the distance, just like a running spring.
+    pub fn for_distance(spring: Spring, distance: f32, epsilon: f32) -> Self {
+        let distance = distance.abs();
+        if distance <= 0.0 {
+            return Self {
+                spring,
+                settling_secs: 0.0,
+                velocity: 0.0,
+            };
+        }
+        let settling = spring.settling_time(-distance, spring.velocity, epsilon);
+        Self {
+            spring,
+            settling_secs: settling.as_secs_f32(),
+            velocity: spring.velocity / distance,
+        }
+    }
+
+    /// Gets the spring this curve follows
+    pub fn spring(&self) -> Spring {
+        self.spring
+    }
+
+    /// Gets the time the spring itself takes to settle, the curve's natural duration
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f32(self.settling_secs)
+    }
+
+    /// Gets the eased progress for time progress `t` (0.0 to 1.0)
+    ///
+    /// Ends exactly on 1.0; the jump from the settled spring is below the settling precision.
+    pub fn ease(&self, t: f32) -> f32 {
+        if t >= 1.0 || self.settling_secs <= 0.0 {
+            return 1.0;
+        }
+        if t <= 0.0 {
+            return 0.0;
+        }
+        let (displacement, _) = self
+            .spring
+            .response(t * self.settling_secs)
+            .apply(-1.0f32, self.velocity);
+        1.0 + displacement
+    }
+}
+
+impl From<Spring> for SpringEasing {
+    fn from(spring: Spring) -> Self {
+        Self::new(spring)
+    }
+}
+
+/// Easing curve applied to a tween or keyframe segment
+///
+/// Prefer the named variants over [`Easing::Function`]: they compare by value, while
+/// function pointers compare by address, which the compiler does not keep unique.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+/// use dioxus_motion::animations::easing::{Curve, EaseKind, StepPosition};
+///
+/// let smooth = Easing::Curve(Curve::Cubic, EaseKind::InOut);
+/// assert_eq!(smooth, Easing::Curve(Curve::Cubic, EaseKind::InOut));
+///
+/// let ticks = Easing::steps(4, StepPosition::End);
+/// assert_eq!(ticks.ease(0.3), 0.25);
+///
+/// let springy = Easing::back(EaseKind::Out, 2.5);
+/// let custom = Easing::custom(|t| t * t);
+/// assert_eq!(custom.ease(0.5), 0.25);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub enum Easing {
+    /// Constant speed
+    #[default]
+    Linear,
+    /// A fixed-shape curve from `easer`
+    Curve(Curve, EaseKind),
+    /// Pulls back before moving, or overshoots the end; `overshoot` 1.70158 matches `easer`
+    Back { kind: EaseKind, overshoot: f32 },
+    /// Oscillates around the start or end with an exponentially decaying `amplitude`
+    /// (at least 1.0) and a `period` in fractions of the duration
+    Elastic {
+        kind: EaseKind,
+        amplitude: f32,
+        period: f32,
+    },
+    /// A CSS `cubic-bezier()` timing function
+    CubicBezier(CubicBezier),
+    /// A CSS `steps(count, position)` timing function
+    Steps { count: u32, position: StepPosition },
+    /// A CSS `linear(...)` timing function
+    PiecewiseLinear(PiecewiseLinear),
+    /// Normalized spring motion
+    Spring(SpringEasing),
+    /// Any function in the `easer` signature, compared by address
+    Function(EasingFn),
+    /// A user closure
+    Custom(CustomEasing),
+}
+
+impl PartialEq for Easing {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Linear, Self::Linear) => true,
+            (Self::Curve(a, kind_a), Self::Curve(b, kind_b)) => a == b && kind_a == kind_b,
+            (
+                Self::Back {
+                    kind: kind_a,
+                    overshoot: a,
+                },
+                Self::Back {
+                    kind: kind_b,
+                    overshoot: b,
+                },
+            ) => kind_a == kind_b && a == b,
+            (
+                Self::Elastic {
+                    kind: kind_a,
+                    amplitude: amplitude_a,
+                    period: period_a,
+                },
+                Self::Elastic {
+                    kind: kind_b,
+                    amplitude: amplitude_b,
+                    period: period_b,
+                },
+            ) => kind_a == kind_b && amplitude_a == amplitude_b && period_a == period_b,
+            (Self::CubicBezier(a), Self::CubicBezier(b)) => a == b,
+            (
+                Self::Steps {
+                    count: count_a,
+                    position: position_a,
+                },
+                Self::Steps {
+                    count: count_b,
+                    position: position_b,
+                },
+            ) => count_a == count_b && position_a == position_b,
+            (Self::PiecewiseLinear(a), Self::PiecewiseLinear(b)) => a == b,
+            (Self::Spring(a), Self::Spring(b)) => a == b,
+            (Self::Function(a), Self::Function(b)) => std::ptr::fn_addr_eq(*a, *b),
+            (Self::Custom(a), Self::Custom(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Easing {
+    /// Default overshoot of [`Easing::Back`], matching `easer` and CSS libraries
+    pub const DEFAULT_OVERSHOOT: f32 = 1.701_58;
+    /// Number of points sampled when a curve has no exact CSS equivalent
+    pub const CSS_SAMPLES: usize = 50;
+
+    /// CSS `steps(count, position)`; a count of zero is treated as one
+    pub fn steps(count: u32, position: StepPosition) -> Self {
+        Self::Steps { count, position }
+    }
+
+    /// CSS `linear(...)` through the given stops, see [`PiecewiseLinear::new`]
+    pub fn linear_stops(stops: impl IntoIterator<Item = impl Into<LinearStop>>) -> Self {
+        Self::PiecewiseLinear(PiecewiseLinear::new(stops))
+    }
+
+    /// Back easing with a custom overshoot
+    pub fn back(kind: EaseKind, overshoot: f32) -> Self {
+        Self::Back { kind, overshoot }
+    }
+
+    /// Elastic easing with a custom amplitude and period
+    pub fn elastic(kind: EaseKind, amplitude: f32, period: f32) -> Self {
+        Self::Elastic {
+            kind,
+            amplitude,
+            period,
+        }
+    }
+
+    /// Easing through a user closure mapping time progress to eased progress
+    pub fn custom(function: impl Fn(f32) -> f32 + Send + Sync + 'static) -> Self {
+        Self::Custom(CustomEasing::new(function))
+    }
+
+    /// Samples the curve at `samples` evenly spaced points into a `linear(...)` curve
+    ///
+    /// At least two samples are taken, always including both ends.
+    pub fn sampled(&self, samples: usize) -> PiecewiseLinear {
+        let last = samples.max(2) - 1;
+        PiecewiseLinear::new((0..=last).map(|i| LinearStop::new(self.ease(i as f32 / last as f32))))
+    }
+
+    /// Gets the CSS `<easing-function>` for this curve
+    ///
+    /// Keywords, `cubic-bezier()`, `steps()` and `linear()` curves convert exactly; all
+    /// other curves, springs included, are sampled into `linear()` with
+    /// [`Easing::CSS_SAMPLES`] points.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// assert_eq!(Easing::from(CubicBezier::EASE_OUT).to_css(), "cubic-bezier(0, 0, 0.58, 1)");
+    ///
+    /// let css = Easing::from(Spring::bouncy()).to_css();
+    /// assert!(css.starts_with("linear(0, "));
+    /// ```
+    pub fn to_css(&self) -> String {
+        match self {
+            Self::Linear => "linear".to_string(),
+            Self::CubicBezier(curve) => format!(
+                "cubic-bezier({}, {}, {}, {})",
+                css_number(curve.x1),
+                css_number(curve.y1),
+                css_number(curve.x2),
+                css_number(curve.y2)
+            ),
+            Self::Steps { count, position } => {
+                let position = match position {
+                    StepPosition::Start => "jump-start",
+                    StepPosition::End => "jump-end",
+                    StepPosition::None => "jump-none",
+                    StepPosition::Both => "jump-both",
+                };
+                format!("steps({}, {position})", count.max(&1))
+            }
+            Self::PiecewiseLinear(curve) => curve.to_css(),
+            _ => self.sampled(Self::CSS_SAMPLES).to_css(),
+        }
+    }
+
+    /// Gets the eased progress for time progress `t` (0.0 to 1.0)
+    pub fn ease(&self, t: f32) -> f32 {
+        match self {
+            Self::Linear => t,
+            Self::Curve(curve, kind) => curve.function(*kind)(t, 0.0, 1.0, 1.0),
+            Self::Back { kind, overshoot } => ease_back(*kind, *overshoot, t),
+            Self::Elastic {
+                kind,
+                amplitude,
+                period,
+            } => ease_elastic(*kind, *amplitude, *period, t),
+            Self::CubicBezier(curve) => curve.ease(t),
+            Self::Steps { count, position } => ease_steps(*count, *position, t),
+            Self::PiecewiseLinear(curve) => curve.ease(t),
+            Self::Spring(curve) => curve.ease(t),
+            Self::Function(function) => function(t, 0.0, 1.0, 1.0),
+            Self::Custom(function) => function.ease(t),
+        }
+    }
+}
+
+impl From<EasingFn> for Easing {
+    fn from(function: EasingFn) -> Self {
+        Self::Function(function)
+    }
+}
+
+impl From<CubicBezier> for Easing {
+    fn from(curve: CubicBezier) -> Self {
+        Self::CubicBezier(curve)
+    }
+}
+
+impl From<PiecewiseLinear> for Easing {
+    fn from(curve: PiecewiseLinear) -> Self {
+        Self::PiecewiseLinear(curve)
+    }
+}
+
+impl From<SpringEasing> for Easing {
+    fn from(curve: SpringEasing) -> Self {
+        Self::Spring(curve)
+    }
+}
+
+impl From<Spring> for Easing {
+    fn from(spring: Spring) -> Self {
+        Self::Spring(SpringEasing::new(spring))
+    }
+}
+
+impl From<CustomEasing> for Easing {
+    fn from(function: CustomEasing) -> Self {
+        Self::Custom(function)
+    }
+}
+
+/// Formats a number for CSS with up to four decimals and no trailing zeros
+fn css_number(value: f32) -> String {
+    let formatted = format!("{value:.4}");
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "-0" | "" => "0".to_string(),
+        _ => trimmed.to_string(),
+    }
+}
+
+/// Penner's back easing with overshoot `s`
+fn ease_back(kind: EaseKind, s: f32, t: f32) -> f32 {
+    let ease_in = |t: f32, s: f32| t * t * ((s + 1.0) * t - s);
+    match kind {
+        EaseKind::In => ease_in(t, s),
+        EaseKind::Out => 1.0 - ease_in(1.0 - t, s),
+        EaseKind::InOut => {
+            // Scaled like `easer` so the halves keep the overall overshoot
+            let s = s * 1.525;
+            if t < 0.5 {
+                ease_in(t * 2.0, s) / 2.0
+            } else {
+                1.0 - ease_in(2.0 - t * 2.0, s) / 2.0
+            }
+        }
+    }
+}
+
+/// Penner's elastic easing with `amplitude` (at least 1.0) and `period`
+fn ease_elastic(kind: EaseKind, amplitude: f32, period: f32, t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t.clamp(0.0, 1.0);
+    }
+    let period = period.max(f32::EPSILON);
+    let amplitude = amplitude.max(1.0);
+    let shift = period / TAU * (1.0 / amplitude).asin();
+    let oscillation = |u: f32| ((u - shift) * TAU / period).sin();
+    // Growing oscillation before the end of an ease-in, `u` counting up to 0
+    let ease_in = |u: f32| -amplitude * 2f32.powf(10.0 * u) * oscillation(u);
+    // Decaying oscillation after the start of an ease-out
+    let ease_out = |u: f32| 1.0 + amplitude * 2f32.powf(-10.0 * u) * oscillation(u);
+
+    match kind {
+        EaseKind::In => ease_in(t - 1.0),
+        EaseKind::Out => ease_out(t),
+        EaseKind::InOut => {
+            if t < 0.5 {
+                ease_in(2.0 * t - 1.0) / 2.0
+            } else {
+                (1.0 + ease_out(2.0 * t - 1.0)) / 2.0
+            }
+        }
+    }
+}
+
+/// CSS step easing following the `steps()` output algorithm
+fn ease_steps(count: u32, position: StepPosition, t: f32) -> f32 {
+    let count = count.max(1) as f32;
+    let mut step = (t * count).floor();
+    if matches!(position, StepPosition::Start | StepPosition::Both) {
+        step += 1.0;
+    }
+
+    let jumps = match position {
+        StepPosition::Start | StepPosition::End => count,
+        StepPosition::Both => count + 1.0,
+        StepPosition::None => (count - 1.0).max(1.0),
+    };
+    if t >= 0.0 && step < 0.0 {
+        step = 0.0;
+    }
+    if t <= 1.0 && step > jumps {
+        step = jumps;
+    }
+    step / jumps
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use easer::functions::{Back, Cubic, Elastic};
+
+    /// Reference values of the CSS keyword curves, solved to f64 precision
+    const BROWSER_SAMPLES: [(CubicBezier, f32, f32); 8] = [
+        (CubicBezier::EASE, 0.25, 0.408_510_6),
+        (CubicBezier::EASE, 0.5, 0.802_403_4),
+        (CubicBezier::EASE, 0.75, 0.960_459),
+        (CubicBezier::EASE_IN, 0.5, 0.315_356_8),
+        (CubicBezier::EASE_OUT, 0.5, 0.684_643_2),
+        (CubicBezier::EASE_IN_OUT, 0.25, 0.129_161_9),
+        (CubicBezier::EASE_IN_OUT, 0.5, 0.5),
+        (CubicBezier::EASE_IN_OUT, 0.75, 0.870_838_1),
+    ];
+
+    #[test]
+    fn test_cubic_bezier_matches_browser_output() {
+        for (curve, t, expected) in BROWSER_SAMPLES {
+            let eased = curve.ease(t);
+            assert!(
+                (eased - expected).abs() < 1e-5,
+                "{curve:?} at {t}: {eased} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cubic_bezier_endpoints_and_linear() {
+        let curves = [
+            CubicBezier::EASE,
+            CubicBezier::new(0.2, 0.0, 0.0, 1.0),
+            CubicBezier::new(0.68, -0.6, 0.32, 1.6),
+        ];
+        for curve in curves {
+            assert!(curve.ease(0.0).abs() < 1e-6);
+            assert!((curve.ease(1.0) - 1.0).abs() < 1e-6);
+        }
+        for step in 0..=20 {
+            let t = step as f32 / 20.0;
+            assert!((CubicBezier::LINEAR.ease(t) - t).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_cubic_bezier_overshoots_and_clamps_x() {
+        let back = CubicBezier::new(0.68, -0.6, 0.32, 1.6);
+        assert!(back.ease(0.1) < 0.0);
+        assert!(back.ease(0.9) > 1.0);
+
+        let clamped = CubicBezier::new(-1.0, 0.0, 2.0, 1.0);
+        assert_eq!((clamped.x1, clamped.x2), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_cubic_bezier_solves_steep_curves() {
+        // Vertical tangents defeat Newton-Raphson; bisection must take over
+        let steep = CubicBezier::new(1.0, 0.0, 0.0, 1.0);
+        let mut previous = 0.0;
+        for step in 1..=100 {
+            let eased = steep.ease(step as f32 / 100.0);
+            assert!(eased >= previous);
+            previous = eased;
+        }
+        assert!((steep.ease(0.5) - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_cubic_bezier_extrapolates_outside_unit_range() {
+        // Flat ends stay flat, other ends continue along their tangent
+        let ease_out = CubicBezier::EASE_OUT;
+        assert!(ease_out.ease(-0.5) < 0.0);
+        assert_eq!(ease_out.ease(1.5), 1.0);
+
+        let ease_in = CubicBezier::EASE_IN;
+        assert_eq!(ease_in.ease(-0.5), 0.0);
+        assert!(ease_in.ease(1.5) > 1.0);
+    }
+
+    fn assert_matches_easer(easing: &Easing, function: EasingFn) {
+        for step in 0..=40 {
+            let t = step as f32 / 40.0;
+            let expected = function(t, 0.0, 1.0, 1.0);
+            assert!(
+                (easing.ease(t) - expected).abs() < 1e-4,
+                "{easing:?} at {t}: {} != {expected}",
+                easing.ease(t)
+            );
+        }
+    }
+
+    #[test]
+    fn test_named_curves_match_easer() {
+        let kinds = [EaseKind::In, EaseKind::Out, EaseKind::InOut];
+        for curve in [Curve::Quad, Curve::Cubic, Curve::Sine, Curve::Bounce] {
+            for kind in kinds {
+                assert_matches_easer(&Easing::Curve(curve, kind), curve.function(kind));
+            }
+        }
+
+        let overshoot = Easing::DEFAULT_OVERSHOOT;
+        assert_matches_easer(&Easing::back(EaseKind::In, overshoot), Back::ease_in);
+        assert_matches_easer(&Easing::back(EaseKind::Out, overshoot), Back::ease_out);
+        assert_matches_easer(&Easing::back(EaseKind::InOut, overshoot), Back::ease_in_out);
+
+        assert_matches_easer(&Easing::elastic(EaseKind::In, 1.0, 0.3), Elastic::ease_in);
+        assert_matches_easer(&Easing::elastic(EaseKind::Out, 1.0, 0.3), Elastic::ease_out);
+        assert_matches_easer(
+            &Easing::elastic(EaseKind::InOut, 1.0, 0.45),
+            Elastic::ease_in_out,
+        );
+    }
+
+    #[test]
+    fn test_parameterized_back_and_elastic() {
+        let gentle = Easing::back(EaseKind::Out, 0.5);
+        let strong = Easing::back(EaseKind::Out, 3.0);
+        let peak = |easing: &Easing| {
+            (0..=100)
+                .map(|i| easing.ease(i as f32 / 100.0))
+                .fold(0.0, f32::max)
+        };
+        assert!(peak(&gentle) < peak(&strong));
+        assert_eq!(strong.ease(1.0), 1.0);
+
+        let loose = Easing::elastic(EaseKind::Out, 2.0, 0.3);
+        let tight = Easing::elastic(EaseKind::Out, 1.0, 0.3);
+        assert!(peak(&loose) > peak(&tight));
+        assert_eq!(loose.ease(0.0), 0.0);
+        assert_eq!(loose.ease(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_steps_follow_css_jump_positions() {
+        let end = Easing::steps(4, StepPosition::End);
+        let samples = [
+            (0.0, 0.0),
+            (0.25, 0.25),
+            (0.3, 0.25),
+            (0.99, 0.75),
+            (1.0, 1.0),
+        ];
+        for (t, expected) in samples {
+            assert_eq!(end.ease(t), expected, "end at {t}");
+        }
+
+        let start = Easing::steps(4, StepPosition::Start);
+        for (t, expected) in [(0.0, 0.25), (0.3, 0.5), (0.99, 1.0), (1.0, 1.0)] {
+            assert_eq!(start.ease(t), expected, "start at {t}");
+        }
+
+        let none = Easing::steps(5, StepPosition::None);
+        for (t, expected) in [(0.0, 0.0), (0.2, 0.25), (0.99, 1.0), (1.0, 1.0)] {
+            assert_eq!(none.ease(t), expected, "none at {t}");
+        }
+
+        let both = Easing::steps(3, StepPosition::Both);
+        for (t, expected) in [(0.0, 0.25), (0.5, 0.5), (1.0, 1.0)] {
+            assert_eq!(both.ease(t), expected, "both at {t}");
+        }
+
+        assert_eq!(Easing::steps(0, StepPosition::End).ease(0.5), 0.0);
+    }
+
+    #[test]
+    fn test_linear_stops_resolve_like_css() {
+        // linear(0, 0.25 75%, 1)
+        let easing = Easing::linear_stops([
+            LinearStop::new(0.0),
+            LinearStop::new(0.25).at(0.75),
+            LinearStop::new(1.0),
+        ]);
+        assert!((easing.ease(0.375) - 0.125).abs() < 1e-6);
+        assert!((easing.ease(0.875) - 0.625).abs() < 1e-6);
+
+        // Missing inputs are spread evenly, decreasing inputs are clamped
+        let spread = PiecewiseLinear::new([0.0, 0.5, 0.6, 1.0]);
+        let inputs: Vec<f32> = spread.points().iter().map(|(input, _)| *input).collect();
+        assert_eq!(inputs, [0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0]);
+        let clamped = PiecewiseLinear::new([
+            LinearStop::new(0.0),
+            LinearStop::new(1.0).at(0.6),
+            LinearStop::new(0.5).at(0.4),
+            LinearStop::new(1.0),
+        ]);
+        assert_eq!(clamped.points()[2], (0.6, 0.5));
+
+        // Equal inputs make a jump, and the ends extrapolate
+        let jump = PiecewiseLinear::new([
+            LinearStop::new(0.0),
+            LinearStop::new(0.0).at(0.5),
+            LinearStop::new(1.0).at(0.5),
+            LinearStop::new(1.0),
+        ]);
+        assert_eq!(jump.ease(0.4), 0.0);
+        assert_eq!(jump.ease(0.5), 1.0);
+        assert_eq!(PiecewiseLinear::new([0.0, 1.0]).ease(1.5), 1.5);
+        assert_eq!(PiecewiseLinear::new([0.5]).ease(0.3), 0.3);
+    }
+
+    #[test]
+    fn test_easing_equality_and_debug() {
+        assert_eq!(
+            Easing::steps(3, StepPosition::End),
+            Easing::steps(3, StepPosition::End)
+        );
+        assert_ne!(
+            Easing::steps(3, StepPosition::End),
+            Easing::steps(3, StepPosition::Start)
+        );
+        assert_eq!(
+            Easing::linear_stops([0.0, 0.3, 1.0]),
+            Easing::linear_stops([0.0, 0.3, 1.0])
+        );
+        assert_ne!(Easing::Linear, Easing::from(CubicBezier::LINEAR));
+
+        // Closures are equal only to themselves and their clones
+        let custom = Easing::custom(|t| t * t);
+        assert_eq!(custom, custom.clone());
+        assert_ne!(custom, Easing::custom(|t| t * t));
+        assert!(format!("{custom:?}").starts_with("Custom(CustomEasing("));
+
+        assert_eq!(
+            format!("{:?}", Easing::steps(2, StepPosition::End)),
+            "Steps { count: 2, position: End }"
+        );
+    }
+
+    #[test]
+    fn test_spring_easing_follows_the_spring() {
+        let spring = Spring::bouncy();
+        let easing = spring.easing();
+        let settle = easing.duration().as_secs_f32();
+        assert!(settle > 0.0);
+
+        assert_eq!(easing.ease(0.0), 0.0);
+        assert_eq!(easing.ease(1.0), 1.0);
+        let peak = (0..=100)
+            .map(|i| easing.ease(i as f32 / 100.0))
+            .fold(0.0, f32::max);
+        assert!(peak > 1.0, "bouncy springs overshoot");
+
+        // Progress at a fraction of the settling time matches the spring's own position
+        let (displacement, _) = spring.response(0.3 * settle).apply(-1.0f32, 0.0);
+        assert!((easing.ease(0.3) - (1.0 + displacement)).abs() < 1e-6);
+        // The jump to the end is within the precision
+        assert!((easing.ease(0.9999) - 1.0).abs() < SpringEasing::PRECISION);
+    }
+
+    #[test]
+    fn test_spring_easing_includes_initial_velocity() {
+        let still = Spring::default().easing();
+        let flung = Spring {
+            velocity: 20.0,
+            ..Default::default()
+        }
+        .easing();
+        assert!(flung.ease(0.02) > still.ease(0.02));
+        assert_ne!(Easing::from(still), Easing::from(flung));
+    }
+
+    #[test]
+    fn test_spring_easing_for_distance() {
+        let spring = Spring::default();
+        let short = SpringEasing::for_distance(spring, 10.0, 0.01);
+        let long = SpringEasing::for_distance(spring, 1000.0, 0.01);
+        assert!(long.duration() > short.duration());
+        assert_eq!(
+            SpringEasing::for_distance(spring, 0.0, 0.01).duration(),
+            Duration::ZERO
+        );
+        assert_eq!(SpringEasing::for_distance(spring, 0.0, 0.01).ease(0.5), 1.0);
+
+        // Same shape as a running spring covering that distance
+        let (displacement, _) = spring
+            .response(0.25 * short.duration().as_secs_f32())
+            .apply(-10.0f32, 0.0);
+        assert!((short.ease(0.25) * 10.0 - (10.0 + displacement)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_css_export() {
+        assert_eq!(Easing::Linear.to_css(), "linear");
+        assert_eq!(
+            Easing::from(CubicBezier::new(0.2, 0.0, 0.0, 1.0)).to_css(),
+            "cubic-bezier(0.2, 0, 0, 1)"
+        );
+        assert_eq!(
+            Easing::steps(4, StepPosition::End).to_css(),
+            "steps(4, jump-end)"
+        );
+        assert_eq!(
+            Easing::linear_stops([
+                LinearStop::new(0.0),
+                LinearStop::new(0.25).at(0.75),
+                LinearStop::new(1.0),
+            ])
+            .to_css(),
+            "linear(0 0%, 0.25 75%, 1 100%)"
+        );
+        assert_eq!(
+            Easing::linear_stops([0.0, 0.5, 1.0]).to_css(),
+            "linear(0, 0.5, 1)"
+        );
+    }
+
+    #[test]
+    fn test_spring_css_round_trips_through_parser() {
+        let easing = Easing::from(Spring::snappy());
+        let css = easing.to_css();
+        assert_eq!(css.matches(',').count(), Easing::CSS_SAMPLES - 1);
+
+        let parsed: Easing = css.parse().unwrap();
+        for step in 0..=20 {
+            let t = step as f32 / 20.0;
+            assert!((parsed.ease(t) - easing.ease(t)).abs() < 0.01, "at {t}");
+        }
+    }
+
+    #[test]
+    fn test_easing_dispatch() {
+        assert_eq!(Easing::default().ease(0.3), 0.3);
+        let function = Easing::from(Cubic::ease_in as EasingFn);
+        assert_eq!(function.ease(0.5), Cubic::ease_in(0.5, 0.0, 1.0, 1.0));
+        let curve = Easing::from(CubicBezier::EASE);
+        assert_eq!(curve.ease(0.5), CubicBezier::EASE.ease(0.5));
+    }
+}
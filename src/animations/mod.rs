@@ -2,6 +2,9 @@ pub mod benchmarks;
 pub mod closure_pool;
 pub mod colors;
 pub mod core;
+pub mod css;
+pub mod decay;
+pub mod easing;
 pub mod epsilon;
 pub mod platform;
 pub mod spring;
@@ -4,7 +4,10 @@
 //! Supports both web (WASM) and native platforms.
 
 use instant::{Duration, Instant};
+use std::cell::RefCell;
 use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
 
 #[cfg(feature = "web")]
 use crate::animations::closure_pool::{create_pooled_closure, register_pooled_callback};
@@ -125,6 +128,183 @@ impl TimeProvider for MotionTime {
 /// Type alias for the default time provider
 pub type Time = MotionTime;
 
+/// Boxed delay future returned by [`MotionClock::delay`]
+pub type ClockDelay = Pin<Box<dyn Future<Output = ()>>>;
+
+/// Runtime-selectable clock used by `use_motion` to drive its animation loop
+///
+/// Wraps the static functions of any [`TimeProvider`] so the clock can be
+/// swapped through Dioxus context without making components generic.
+/// When no clock is provided, [`MotionTime`] is used.
+///
+/// # Examples
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_motion::{ManualClock, MotionClock};
+///
+/// fn app() -> Element {
+///     // Every `use_motion` below this component now runs on the manual clock
+///     use_context_provider(MotionClock::of::<ManualClock>);
+///     rsx! {}
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct MotionClock {
+    now: fn() -> Instant,
+    delay: fn(Duration) -> ClockDelay,
+}
+
+impl MotionClock {
+    /// Creates a clock backed by the given time provider
+    pub fn of<C: TimeProvider + 'static>() -> Self {
+        Self {
+            now: C::now,
+            delay: boxed_delay::<C>,
+        }
+    }
+
+    /// Returns the current instant of the underlying provider
+    pub fn now(&self) -> Instant {
+        (self.now)()
+    }
+
+    /// Creates a future that completes after the specified duration
+    pub fn delay(&self, duration: Duration) -> ClockDelay {
+        (self.delay)(duration)
+    }
+}
+
+impl Default for MotionClock {
+    fn default() -> Self {
+        Self::of::<MotionTime>()
+    }
+}
+
+impl std::fmt::Debug for MotionClock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MotionClock").finish_non_exhaustive()
+    }
+}
+
+fn boxed_delay<C: TimeProvider + 'static>(duration: Duration) -> ClockDelay {
+    Box::pin(C::delay(duration))
+}
+
+/// State behind [`ManualClock`]: a fixed origin plus a manually advanced offset
+struct ManualClockState {
+    origin: Instant,
+    offset: Duration,
+    sleepers: Vec<(Duration, Waker)>,
+}
+
+impl ManualClockState {
+    fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+            offset: Duration::ZERO,
+            sleepers: Vec::new(),
+        }
+    }
+}
+
+// Thread-local so every VirtualDom on a test thread shares one deterministic timeline
+thread_local! {
+    static MANUAL_CLOCK: RefCell<ManualClockState> = RefCell::new(ManualClockState::new());
+}
+
+/// Virtual clock for deterministic animation tests
+///
+/// Time only moves when [`ManualClock::advance`] is called, and delay futures
+/// complete once the clock has been advanced past their deadline. The clock is
+/// thread-local, so tests running in parallel do not interfere with each other.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::{Duration, ManualClock, TimeProvider};
+///
+/// ManualClock::reset();
+/// let start = ManualClock::now();
+/// ManualClock::advance(Duration::from_millis(16));
+/// assert_eq!(ManualClock::now() - start, Duration::from_millis(16));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ManualClock;
+
+impl ManualClock {
+    /// Moves the clock forward and wakes every delay whose deadline has passed
+    pub fn advance(duration: Duration) {
+        let ready = MANUAL_CLOCK.with(|clock| {
+            let mut clock = clock.borrow_mut();
+            clock.offset += duration;
+            let offset = clock.offset;
+            let (ready, pending): (Vec<_>, Vec<_>) = clock
+                .sleepers
+                .drain(..)
+                .partition(|(deadline, _)| *deadline <= offset);
+            clock.sleepers = pending;
+            ready
+        });
+
+        // Wake outside of the borrow so woken tasks may immediately poll the clock again
+        for (_, waker) in ready {
+            waker.wake();
+        }
+    }
+
+    /// Returns the total time the clock has been advanced since the last reset
+    pub fn elapsed() -> Duration {
+        MANUAL_CLOCK.with(|clock| clock.borrow().offset)
+    }
+
+    /// Returns the number of delay futures currently waiting on the clock
+    pub fn pending_delays() -> usize {
+        MANUAL_CLOCK.with(|clock| clock.borrow().sleepers.len())
+    }
+
+    /// Resets the clock to zero and drops all pending delays
+    pub fn reset() {
+        MANUAL_CLOCK.with(|clock| {
+            *clock.borrow_mut() = ManualClockState::new();
+        });
+    }
+}
+
+impl TimeProvider for ManualClock {
+    fn now() -> Instant {
+        MANUAL_CLOCK.with(|clock| {
+            let clock = clock.borrow();
+            clock.origin + clock.offset
+        })
+    }
+
+    fn delay(duration: Duration) -> impl Future<Output = ()> {
+        ManualDelay {
+            deadline: Self::elapsed() + duration,
+        }
+    }
+}
+
+/// Future that completes once [`ManualClock`] reaches its deadline
+struct ManualDelay {
+    deadline: Duration,
+}
+
+impl Future for ManualDelay {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        MANUAL_CLOCK.with(|clock| {
+            let mut clock = clock.borrow_mut();
+            if clock.offset >= self.deadline {
+                Poll::Ready(())
+            } else {
+                clock.sleepers.push((self.deadline, cx.waker().clone()));
+                Poll::Pending
+            }
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,6 +323,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_manual_clock_advance() {
+        ManualClock::reset();
+        let start = ManualClock::now();
+
+        // Time does not move on its own
+        std::thread::sleep(Duration::from_millis(1));
+        assert_eq!(ManualClock::now(), start);
+
+        ManualClock::advance(Duration::from_millis(16));
+        assert_eq!(
+            ManualClock::now().duration_since(start),
+            Duration::from_millis(16)
+        );
+        assert_eq!(ManualClock::elapsed(), Duration::from_millis(16));
+
+        ManualClock::reset();
+        assert_eq!(ManualClock::elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_manual_clock_delay_completes_after_advance() {
+        use futures_util::FutureExt;
+
+        ManualClock::reset();
+        let mut delay = Box::pin(ManualClock::delay(Duration::from_millis(30)));
+
+        assert!((&mut delay).now_or_never().is_none());
+        assert_eq!(ManualClock::pending_delays(), 1);
+
+        ManualClock::advance(Duration::from_millis(16));
+        assert!((&mut delay).now_or_never().is_none());
+
+        ManualClock::advance(Duration::from_millis(16));
+        assert!((&mut delay).now_or_never().is_some());
+        assert_eq!(ManualClock::pending_delays(), 0);
+    }
+
+    #[test]
+    fn test_motion_clock_dispatches_to_provider() {
+        ManualClock::reset();
+        let clock = MotionClock::of::<ManualClock>();
+        let start = clock.now();
+
+        ManualClock::advance(Duration::from_millis(5));
+        assert_eq!(clock.now().duration_since(start), Duration::from_millis(5));
+    }
+
     #[cfg(not(feature = "web"))]
     #[tokio::test]
     async fn test_desktop_sleep_threshold_optimization() {
@@ -3,6 +3,11 @@
 //! Provides a physical spring model for smooth, natural-looking animations.
 //! Based on Hooke's law with damping for realistic motion.
 
+use crate::Duration;
+use crate::animations::core::{self, Animatable};
+use crate::animations::easing::SpringEasing;
+use std::f32::consts::TAU;
+
 /// Configuration for spring-based animations
 ///
 /// Uses a mass-spring-damper system to create natural motion.
@@ -15,6 +20,7 @@
 ///     damping: 10.0,     // Higher values = less bounce
 ///     mass: 1.0,         // Higher values = more inertia
 ///     velocity: 0.0,     // Initial velocity
+///     ..Default::default()
 /// };
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -32,8 +38,25 @@ pub struct Spring {
     pub mass: f32,
 
     /// Initial velocity (default: 0.0)
-    /// Can be set for pre-existing motion
+    /// Speed towards the target when the animation starts from rest; use
+    /// `Motion::animate_to_with_velocity` for a typed velocity
     pub velocity: f32,
+
+    /// How the spring motion is computed (default: numeric integration)
+    pub solver: SpringSolver,
+}
+
+/// Strategy used to advance a spring animation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpringSolver {
+    /// Integrates the spring every frame with RK4 (sub-stepped on web)
+    #[default]
+    Numeric,
+    /// Evaluates the closed-form solution at the time since the animation started
+    ///
+    /// Output depends only on elapsed time, so it is identical on every platform and
+    /// frame rate, and the spring can be seeked like a tween.
+    Analytic,
 }
 
 /// Default spring configuration for general-purpose animations
@@ -44,10 +67,343 @@ impl Default for Spring {
             damping: 10.0,
             mass: 1.0,
             velocity: 0.0,
+            solver: SpringSolver::Numeric,
         }
     }
 }
 
+impl Spring {
+    /// Creates a spring from a perceptual duration and bounciness
+    ///
+    /// `duration` is the time the spring takes to visually reach its target and `bounce`
+    /// ranges from `-1.0` to `1.0`: `0.0` is critically damped with no overshoot, positive
+    /// values overshoot and oscillate, and negative values settle more slowly without
+    /// overshooting. Bounce is clamped to ±0.99 and mass is always 1.0.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// let spring = Spring::from_duration_bounce(Duration::from_millis(400), 0.2);
+    /// assert!((spring.damping_ratio() - 0.8).abs() < 1e-4);
+    /// ```
+    pub fn from_duration_bounce(duration: Duration, bounce: f32) -> Self {
+        let bounce = bounce.clamp(-MAX_BOUNCE, MAX_BOUNCE);
+        let damping_ratio = if bounce >= 0.0 {
+            1.0 - bounce
+        } else {
+            1.0 / (1.0 + bounce)
+        };
+        Self::from_response_damping_ratio(duration, damping_ratio)
+    }
+
+    /// Creates a spring from its response (period of the undamped oscillation) and damping ratio
+    ///
+    /// A damping ratio below 1.0 oscillates, 1.0 is critically damped and above 1.0 is
+    /// overdamped. Mass is always 1.0.
+    pub fn from_response_damping_ratio(response: Duration, damping_ratio: f32) -> Self {
+        let response = response.as_secs_f32().max(MIN_RESPONSE_SECS);
+        let mass = 1.0;
+        Self {
+            stiffness: (TAU / response).powi(2) * mass,
+            damping: 2.0 * TAU * damping_ratio.max(0.0) * mass / response,
+            mass,
+            ..Default::default()
+        }
+    }
+
+    /// Gets the perceptual duration and bounce of the spring
+    ///
+    /// Inverse of [`Spring::from_duration_bounce`].
+    pub fn duration_bounce(&self) -> (Duration, f32) {
+        let damping_ratio = self.damping_ratio();
+        let bounce = if damping_ratio <= 1.0 {
+            1.0 - damping_ratio
+        } else {
+            1.0 / damping_ratio - 1.0
+        };
+        (self.response_period(), bounce)
+    }
+
+    /// Gets the response (period of the undamped oscillation) and damping ratio
+    ///
+    /// Inverse of [`Spring::from_response_damping_ratio`].
+    pub fn response_damping_ratio(&self) -> (Duration, f32) {
+        (self.response_period(), self.damping_ratio())
+    }
+
+    /// Gets the period of the undamped oscillation
+    ///
+    /// Springs without a positive stiffness and mass never oscillate and report
+    /// [`Duration::MAX`].
+    pub fn response_period(&self) -> Duration {
+        Duration::try_from_secs_f32(TAU / self.natural_frequency()).unwrap_or(Duration::MAX)
+    }
+
+    /// Soft, slow spring with a little overshoot (stiffness 120, damping 14)
+    pub fn gentle() -> Self {
+        Self {
+            stiffness: 120.0,
+            damping: 14.0,
+            ..Default::default()
+        }
+    }
+
+    /// Quick spring with a small bounce (0.5s, bounce 0.15)
+    pub fn snappy() -> Self {
+        Self::from_duration_bounce(Duration::from_millis(500), 0.15)
+    }
+
+    /// Playful spring with a noticeable bounce (0.5s, bounce 0.3)
+    pub fn bouncy() -> Self {
+        Self::from_duration_bounce(Duration::from_millis(500), 0.3)
+    }
+
+    /// Firm spring that settles quickly (stiffness 210, damping 20)
+    pub fn stiff() -> Self {
+        Self {
+            stiffness: 210.0,
+            damping: 20.0,
+            ..Default::default()
+        }
+    }
+
+    /// Loose spring that oscillates several times (stiffness 180, damping 12)
+    pub fn wobbly() -> Self {
+        Self {
+            stiffness: 180.0,
+            damping: 12.0,
+            ..Default::default()
+        }
+    }
+
+    /// Estimates how long the spring takes to settle
+    ///
+    /// Returns the time after which both the displacement from the target and the velocity
+    /// stay below `epsilon`, which is when a running spring animation completes. Springs that
+    /// never settle (no damping, no stiffness, or so overdamped that they barely move) are
+    /// capped at one minute.
+    ///
+    /// # Arguments
+    /// * `displacement` - Initial offset from the target (start minus target)
+    /// * `velocity` - Initial velocity
+    /// * `epsilon` - Completion threshold
+    pub fn settling_time(&self, displacement: f32, velocity: f32, epsilon: f32) -> Duration {
+        let epsilon = epsilon.max(f32::EPSILON);
+        let settled = |t: f32| {
+            let (x, v) = self.response(t).apply(displacement, velocity);
+            x.abs() < epsilon && v.abs() < epsilon
+        };
+
+        let Some(horizon) = self.settling_horizon(displacement, velocity, epsilon) else {
+            return Duration::from_secs_f32(MAX_SETTLING_SECS);
+        };
+
+        // The envelope is conservative: scan back for the last unsettled moment
+        let step = (horizon / SETTLING_SCAN_STEPS).max(1e-4);
+        let mut t = horizon;
+        while t > 0.0 && settled(t) {
+            t -= step;
+        }
+        if t <= 0.0 {
+            return Duration::ZERO;
+        }
+        Duration::from_secs_f32((t + step).min(horizon))
+    }
+
+    /// Finds a time after which the decay envelope keeps the spring within `epsilon`
+    fn settling_horizon(&self, displacement: f32, velocity: f32, epsilon: f32) -> Option<f32> {
+        let omega = self.natural_frequency();
+        if !(omega.is_finite() && omega > 0.0) {
+            return None;
+        }
+        let zeta = self.damping_ratio();
+        if zeta <= 0.0 {
+            return None;
+        }
+
+        // Bound on |x| and |v| as `amplitude * (1 + growth * t) * e^(-rate * t)`
+        let (amplitude, growth, rate) = if (zeta - 1.0).abs() < CRITICAL_DAMPING_TOLERANCE {
+            let drift = (velocity + omega * displacement).abs();
+            let amplitude = displacement.abs().max(velocity.abs()).max(f32::EPSILON);
+            (amplitude, drift * omega.max(1.0) / amplitude, omega)
+        } else if zeta < 1.0 {
+            let damped = omega * (1.0 - zeta * zeta).sqrt();
+            let phase = (velocity + zeta * omega * displacement) / damped;
+            let radius = (displacement * displacement + phase * phase).sqrt();
+            (radius * omega.max(1.0), 0.0, zeta * omega)
+        } else {
+            let (r1, r2) = overdamped_roots(omega, zeta);
+            let a = (velocity - r2 * displacement) / (r1 - r2);
+            let b = displacement - a;
+            let amplitude = (a.abs() + b.abs()).max(a.abs() * -r1 + b.abs() * -r2);
+            (amplitude, 0.0, -r1)
+        };
+
+        if amplitude <= epsilon && growth == 0.0 {
+            return Some(0.0);
+        }
+        if !(rate.is_finite() && rate > 0.0) {
+            // The slowest mode decays too slowly for `f32` to tell it apart from zero
+            return None;
+        }
+
+        // The envelope is decreasing past its peak at t = 1/rate, so search from there
+        let bound = |t: f32| amplitude * (1.0 + growth * t) * (-rate * t).exp();
+        let mut t = if growth > 0.0 { 1.0 / rate } else { 0.0 };
+        let mut span = 1.0 / rate;
+        while bound(t) >= epsilon {
+            t += span;
+            span *= 2.0;
+            if t > MAX_SETTLING_SECS {
+                return None;
+            }
+        }
+        Some(t)
+    }
+
+    /// Gets [`Spring::velocity`] as a typed velocity pointing from `from` towards `to`
+    ///
+    /// Positive values move towards the target, negative values away from it.
+    pub fn velocity_towards<T: Animatable>(&self, from: T, to: T) -> T {
+        core::velocity_towards(self.velocity, from, to)
+    }
+
+    /// Gets the spring's motion as an easing curve for fixed-duration tweens
+    ///
+    /// See [`SpringEasing`] for details.
+    pub fn easing(&self) -> SpringEasing {
+        SpringEasing::new(*self)
+    }
+
+    /// Sets the solver used to advance the spring
+    pub fn with_solver(mut self, solver: SpringSolver) -> Self {
+        self.solver = solver;
+        self
+    }
+
+    /// Uses the closed-form [`SpringSolver::Analytic`] solver
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// let config = AnimationConfig::new(AnimationMode::Spring(Spring::default().analytic()));
+    /// ```
+    pub fn analytic(self) -> Self {
+        self.with_solver(SpringSolver::Analytic)
+    }
+
+    /// Gets the undamped angular frequency (rad/s)
+    pub fn natural_frequency(&self) -> f32 {
+        (self.stiffness / self.mass).sqrt()
+    }
+
+    /// Gets the damping ratio (below 1.0 oscillates, 1.0 is critical, above 1.0 is overdamped)
+    pub fn damping_ratio(&self) -> f32 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// Solves the damped harmonic oscillator `t` seconds after the animation started
+    ///
+    /// Handles under-, critically- and over-damped springs in closed form.
+    pub fn response(&self, t: f32) -> SpringResponse {
+        let omega = self.natural_frequency();
+
+        if !(omega.is_finite() && omega > 0.0) {
+            // No restoring force: velocity decays under damping alone
+            let gamma = self.damping / self.mass;
+            let decay = (-gamma * t).exp();
+            let travel = if gamma > 0.0 {
+                (1.0 - decay) / gamma
+            } else {
+                t
+            };
+            return SpringResponse {
+                from_displacement: (1.0, 0.0),
+                from_velocity: (travel, decay),
+            };
+        }
+
+        let zeta = self.damping_ratio();
+
+        if (zeta - 1.0).abs() < CRITICAL_DAMPING_TOLERANCE {
+            let decay = (-omega * t).exp();
+            SpringResponse {
+                from_displacement: ((1.0 + omega * t) * decay, -omega * omega * t * decay),
+                from_velocity: (t * decay, (1.0 - omega * t) * decay),
+            }
+        } else if zeta < 1.0 {
+            let damped = omega * (1.0 - zeta * zeta).sqrt();
+            let decay = (-zeta * omega * t).exp();
+            let (sin, cos) = (damped * t).sin_cos();
+            SpringResponse {
+                from_displacement: (
+                    decay * (cos + zeta * omega / damped * sin),
+                    -decay * omega * omega / damped * sin,
+                ),
+                from_velocity: (
+                    decay * sin / damped,
+                    decay * (cos - zeta * omega / damped * sin),
+                ),
+            }
+        } else {
+            let (r1, r2) = overdamped_roots(omega, zeta);
+            let (e1, e2) = ((r1 * t).exp(), (r2 * t).exp());
+            let spread = r1 - r2;
+            SpringResponse {
+                from_displacement: ((r1 * e2 - r2 * e1) / spread, r1 * r2 * (e2 - e1) / spread),
+                from_velocity: ((e1 - e2) / spread, (r1 * e1 - r2 * e2) / spread),
+            }
+        }
+    }
+}
+
+/// Gets the slow and fast decay rates `(r1, r2)` of an overdamped spring, both negative
+///
+/// `r1` comes from `r1 * r2 = omega^2` rather than `-zeta * omega + root`, which cancels to
+/// zero or even a positive rate for heavily overdamped springs.
+fn overdamped_roots(omega: f32, zeta: f32) -> (f32, f32) {
+    let r2 = -omega * (zeta + (zeta * zeta - 1.0).sqrt());
+    (omega * omega / r2, r2)
+}
+
+/// Largest bounce magnitude accepted by [`Spring::from_duration_bounce`]
+const MAX_BOUNCE: f32 = 0.99;
+
+/// Shortest response accepted when deriving springs, avoiding infinite stiffness
+const MIN_RESPONSE_SECS: f32 = 0.001;
+
+/// Upper bound for [`Spring::settling_time`], in seconds
+const MAX_SETTLING_SECS: f32 = 60.0;
+
+/// Resolution of the backward scan in [`Spring::settling_time`]
+const SETTLING_SCAN_STEPS: f32 = 4096.0;
+
+/// Damping ratios this close to 1.0 use the critically damped solution
+const CRITICAL_DAMPING_TOLERANCE: f32 = 1e-4;
+
+/// Closed-form spring state at a point in time
+///
+/// The solution is linear in the initial conditions: each field holds the
+/// `(displacement, velocity)` produced by a unit initial displacement or a unit
+/// initial velocity respectively.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringResponse {
+    /// Contribution of the initial displacement from the target
+    pub from_displacement: (f32, f32),
+    /// Contribution of the initial velocity
+    pub from_velocity: (f32, f32),
+}
+
+impl SpringResponse {
+    /// Combines the response with initial conditions into `(displacement, velocity)`
+    pub fn apply<T: Animatable>(&self, displacement: T, velocity: T) -> (T, T) {
+        (
+            displacement * self.from_displacement.0 + velocity * self.from_velocity.0,
+            displacement * self.from_displacement.1 + velocity * self.from_velocity.1,
+        )
+    }
+}
+
 /// Represents the current state of a spring animation
 ///
 /// Used to track whether the spring is still moving or has settled
@@ -79,11 +435,253 @@ mod tests {
             damping: 20.0,
             mass: 2.0,
             velocity: 5.0,
+            solver: SpringSolver::Analytic,
         };
 
         assert_eq!(spring.stiffness, 200.0);
         assert_eq!(spring.damping, 20.0);
         assert_eq!(spring.mass, 2.0);
         assert_eq!(spring.velocity, 5.0);
+        assert_eq!(spring.solver, SpringSolver::Analytic);
+    }
+
+    /// Integrates the spring numerically with tiny steps as a reference
+    fn reference(spring: &Spring, x0: f32, v0: f32, t: f32) -> (f32, f32) {
+        let steps = 100_000;
+        let dt = t / steps as f32;
+        let (mut x, mut v) = (x0 as f64, v0 as f64);
+        let (k, c, m) = (
+            spring.stiffness as f64,
+            spring.damping as f64,
+            spring.mass as f64,
+        );
+        for _ in 0..steps {
+            let a = (-k * x - c * v) / m;
+            v += a * dt as f64;
+            x += v * dt as f64;
+        }
+        (x as f32, v as f32)
+    }
+
+    fn assert_matches_reference(spring: Spring) {
+        for t in [0.05, 0.2, 0.5, 1.0] {
+            let (x, v) = spring.response(t).apply(1.0f32, 3.0f32);
+            let (rx, rv) = reference(&spring, 1.0, 3.0, t);
+            assert!(
+                (x - rx).abs() < 2e-3,
+                "{spring:?} x({t}) = {x}, expected {rx}"
+            );
+            assert!(
+                (v - rv).abs() < 2e-2,
+                "{spring:?} v({t}) = {v}, expected {rv}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_response_underdamped() {
+        let spring = Spring::default();
+        assert!(spring.damping_ratio() < 1.0);
+        assert_matches_reference(spring);
+    }
+
+    #[test]
+    fn test_response_critically_damped() {
+        let spring = Spring {
+            stiffness: 100.0,
+            damping: 20.0,
+            ..Default::default()
+        };
+        assert_eq!(spring.damping_ratio(), 1.0);
+        assert_matches_reference(spring);
+    }
+
+    #[test]
+    fn test_response_overdamped() {
+        let spring = Spring {
+            stiffness: 100.0,
+            damping: 50.0,
+            mass: 2.0,
+            ..Default::default()
+        };
+        assert!(spring.damping_ratio() > 1.0);
+        assert_matches_reference(spring);
+    }
+
+    #[test]
+    fn test_from_response_damping_ratio_constants() {
+        let spring = Spring::from_response_damping_ratio(Duration::from_secs(1), 0.5);
+        let expected_stiffness = TAU * TAU;
+        assert!((spring.stiffness - expected_stiffness).abs() < 1e-3);
+        assert!((spring.damping - TAU).abs() < 1e-4);
+        assert_eq!(spring.mass, 1.0);
+
+        let (response, damping_ratio) = spring.response_damping_ratio();
+        assert!((response.as_secs_f32() - 1.0).abs() < 1e-5);
+        assert!((damping_ratio - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_from_duration_bounce_round_trips() {
+        for bounce in [-0.5, 0.0, 0.15, 0.3, 0.7] {
+            let spring = Spring::from_duration_bounce(Duration::from_millis(350), bounce);
+            let (duration, derived_bounce) = spring.duration_bounce();
+            assert!((duration.as_secs_f32() - 0.35).abs() < 1e-5);
+            assert!((derived_bounce - bounce).abs() < 1e-4, "bounce {bounce}");
+        }
+
+        // Zero bounce is critically damped, negative bounce overdamped
+        let critical = Spring::from_duration_bounce(Duration::from_millis(350), 0.0);
+        assert!((critical.damping_ratio() - 1.0).abs() < 1e-5);
+        let overdamped = Spring::from_duration_bounce(Duration::from_millis(350), -0.5);
+        assert!((overdamped.damping_ratio() - 2.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_response_period_without_stiffness_or_mass() {
+        for (stiffness, mass) in [(0.0, 1.0), (-100.0, 1.0), (100.0, -1.0)] {
+            let spring = Spring {
+                stiffness,
+                mass,
+                ..Default::default()
+            };
+            assert_eq!(
+                spring.response_period(),
+                Duration::MAX,
+                "{stiffness} {mass}"
+            );
+            assert_eq!(spring.duration_bounce().0, Duration::MAX);
+            assert_eq!(spring.response_damping_ratio().0, Duration::MAX);
+        }
+    }
+
+    #[test]
+    fn test_extremely_overdamped_springs_never_settle() {
+        let spring = Spring {
+            stiffness: 1e-6,
+            damping: 1e6,
+            ..Default::default()
+        };
+        assert_eq!(
+            spring.settling_time(-100.0, 0.0, 0.01),
+            Duration::from_secs_f32(MAX_SETTLING_SECS)
+        );
+    }
+
+    #[test]
+    fn test_presets() {
+        assert_eq!(
+            (Spring::gentle().stiffness, Spring::gentle().damping),
+            (120.0, 14.0)
+        );
+        assert_eq!(
+            (Spring::stiff().stiffness, Spring::stiff().damping),
+            (210.0, 20.0)
+        );
+        assert_eq!(
+            (Spring::wobbly().stiffness, Spring::wobbly().damping),
+            (180.0, 12.0)
+        );
+
+        let snappy = Spring::snappy();
+        assert!((snappy.stiffness - 157.9137).abs() < 1e-2);
+        assert!((snappy.damping - 21.3628).abs() < 1e-3);
+
+        let bouncy = Spring::bouncy();
+        assert!((bouncy.stiffness - 157.9137).abs() < 1e-2);
+        assert!((bouncy.damping - 17.5929).abs() < 1e-3);
+
+        // Presets are ordered from most to least damped
+        assert!(Spring::stiff().damping_ratio() > Spring::gentle().damping_ratio());
+        assert!(Spring::gentle().damping_ratio() > Spring::wobbly().damping_ratio());
+        assert!(snappy.damping_ratio() > bouncy.damping_ratio());
+        for preset in [
+            Spring::gentle(),
+            Spring::snappy(),
+            Spring::bouncy(),
+            Spring::stiff(),
+            Spring::wobbly(),
+        ] {
+            assert!(preset.damping_ratio() < 1.0);
+        }
+    }
+
+    /// Checks the settling time against a dense forward scan of the closed-form solution
+    fn assert_settles_at(spring: Spring, displacement: f32, velocity: f32) {
+        let epsilon = 0.01;
+        let settle = spring
+            .settling_time(displacement, velocity, epsilon)
+            .as_secs_f32();
+
+        let mut last_unsettled = 0.0f32;
+        let mut t = 0.0f32;
+        while t < settle + 2.0 {
+            let (x, v) = spring.response(t).apply(displacement, velocity);
+            if x.abs() >= epsilon || v.abs() >= epsilon {
+                last_unsettled = t;
+            }
+            t += 0.0005;
+        }
+        assert!(
+            (settle - last_unsettled).abs() < 0.01,
+            "{spring:?}: estimated {settle}s, observed {last_unsettled}s"
+        );
+    }
+
+    #[test]
+    fn test_settling_time_matches_motion() {
+        assert_settles_at(Spring::default(), -100.0, 0.0);
+        assert_settles_at(Spring::wobbly(), -1.0, 5.0);
+        assert_settles_at(
+            Spring::from_duration_bounce(Duration::from_millis(300), 0.0),
+            -50.0,
+            0.0,
+        );
+        assert_settles_at(
+            Spring::from_duration_bounce(Duration::from_millis(300), -0.6),
+            -50.0,
+            20.0,
+        );
+    }
+
+    #[test]
+    fn test_settling_time_scales_with_parameters() {
+        let settle = |spring: Spring| spring.settling_time(-100.0, 0.0, 0.01);
+
+        // Stiffer and more damped springs settle sooner, heavier ones later
+        assert!(settle(Spring::stiff()) < settle(Spring::wobbly()));
+        let heavy = Spring {
+            mass: 4.0,
+            ..Default::default()
+        };
+        assert!(settle(heavy) > settle(Spring::default()));
+        assert!(Spring::default().settling_time(-1.0, 0.0, 0.01) < settle(Spring::default()));
+
+        assert_eq!(
+            Spring::default().settling_time(0.0, 0.0, 0.01),
+            Duration::ZERO
+        );
+        let undamped = Spring {
+            damping: 0.0,
+            ..Default::default()
+        };
+        assert_eq!(settle(undamped), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_velocity_towards_target() {
+        let spring = Spring {
+            velocity: 5.0,
+            ..Default::default()
+        };
+        assert_eq!(spring.velocity_towards(10.0f32, 0.0), -5.0);
+        assert_eq!(spring.velocity_towards(0.0f32, 0.0), 0.0);
+        assert_eq!(Spring::default().velocity_towards(0.0f32, 10.0), 0.0);
+    }
+
+    #[test]
+    fn test_response_starts_at_initial_conditions() {
+        let response = Spring::default().response(0.0);
+        assert_eq!(response.apply(2.0f32, -1.0f32), (2.0, -1.0));
     }
 }
@@ -5,13 +5,17 @@
 
 use crate::Duration;
 use crate::animations::core::{Animatable, AnimationMode};
-use crate::animations::spring::{Spring, SpringState};
-use crate::keyframes::KeyframeAnimation;
+use crate::animations::decay::Decay;
+use crate::animations::spring::{Spring, SpringSolver, SpringState};
+use crate::keyframes::KeyframeTrack;
 use crate::pool::{ConfigHandle, global};
 use crate::prelude::{AnimationConfig, LoopMode, Tween};
 use crate::sequence::AnimationSequence;
 use std::sync::Arc;
 
+/// Smallest frame delta (in seconds) used when stepping animations offline
+pub(crate) const MIN_DELTA: f32 = 1.0 / 240.0;
+
 /// Animation state enum that represents the current mode of animation
 /// This replaces complex branching logic with efficient state dispatch
 #[derive(Clone)]
@@ -30,7 +34,7 @@ pub enum AnimationState<T: Animatable> {
     },
     /// Keyframe animation is active
     Keyframes {
-        animation: Arc<KeyframeAnimation<T>>,
+        animation: Arc<KeyframeTrack<T>>,
         config_handle: ConfigHandle,
     },
 }
@@ -58,10 +62,7 @@ impl<T: Animatable + Send + 'static> AnimationState<T> {
     }
 
     /// Creates a new keyframes state
-    pub fn new_keyframes(
-        animation: Arc<KeyframeAnimation<T>>,
-        config_handle: ConfigHandle,
-    ) -> Self {
+    pub fn new_keyframes(animation: Arc<KeyframeTrack<T>>, config_handle: ConfigHandle) -> Self {
         Self::Keyframes {
             animation,
             config_handle,
@@ -92,7 +93,7 @@ impl<T: Animatable + Send + 'static> AnimationState<T> {
                 mode,
                 config_handle,
             } => {
-                let mode = *mode;
+                let mode = mode.clone();
                 let config_handle = config_handle.clone();
                 self.update_running(mode, &config_handle, dt, motion)
             }
@@ -123,36 +124,37 @@ impl<T: Animatable + Send + 'static> AnimationState<T> {
         dt: f32,
         motion: &mut crate::Motion<T>,
     ) -> bool {
-        // Skip updates for imperceptible changes
-        const MIN_DELTA: f32 = 1.0 / 240.0;
-        if dt < MIN_DELTA {
-            return true;
-        }
-
         // Get config from handle
         let config = global::get_config_ref(config_handle).unwrap_or_default();
+        let dt = dt * config.playback_rate();
 
         // Handle delay
         if motion.delay_elapsed < config.delay {
-            motion.delay_elapsed += Duration::from_secs_f32(dt);
+            motion.delay_elapsed = advance_elapsed(motion.delay_elapsed, dt.abs());
             return true;
         }
 
         let completed = match mode {
             AnimationMode::Spring(spring) => {
-                let spring_result = self.update_spring(motion, spring, dt);
+                let spring_result = self.update_spring(motion, spring, dt.abs());
                 matches!(spring_result, SpringState::Completed)
             }
             AnimationMode::Tween(tween) => self.update_tween(motion, tween, dt),
+            AnimationMode::Decay(decay) => {
+                self.update_decay(motion, decay, config_handle, dt.abs())
+            }
         };
 
         if completed {
             // Check if this is part of a sequence
-            if let Some(sequence) = motion.sequence.as_ref() {
+            if let Some(sequence) = motion.sequence.clone() {
+                // Steps with their own loop mode repeat before the sequence moves on
+                if Self::repeat_sequence_step(motion, &config) {
+                    return true;
+                }
+
                 // This is a sequence step completion - advance to next step
-                let sequence_clone = sequence.clone();
-                if let Some(new_mode) =
-                    self.advance_sequence_step(&sequence_clone, config_handle, motion)
+                if let Some(new_mode) = self.advance_sequence_step(&sequence, config_handle, motion)
                 {
                     // Successfully advanced to next step
                     *self = Self::Running {
@@ -181,29 +183,25 @@ impl<T: Animatable + Send + 'static> AnimationState<T> {
         config_handle: &ConfigHandle,
         motion: &mut crate::Motion<T>,
     ) -> Option<AnimationMode> {
-        if sequence.advance_step() {
-            // Successfully advanced to next step
-            if let Some(step) = sequence.current_step_data() {
-                let target = step.target;
-                let config = (*step.config).clone();
-                let mode = config.mode;
-
-                // Update motion for new step
-                motion.initial = motion.current;
-                motion.target = target;
-                motion.running = true;
-                motion.elapsed = Duration::default();
-                motion.delay_elapsed = Duration::default();
-                motion.velocity = T::zero();
-
-                // Update config handle for new step
-                global::modify_config(config_handle, |pooled_config| {
-                    *pooled_config = config;
-                });
-
-                return Some(mode);
-            }
+        sequence.complete_step();
+        // Call steps run while the next step begins, so the sequence may end here too
+        let mut next = if sequence.advance_step() {
+            sequence.begin_step()
         } else {
+            None
+        };
+        if next.is_none() && sequence.begin_loop() {
+            // Repeating passes start over from the origin, alternating ones turn around
+            if !matches!(
+                sequence.loop_mode(),
+                LoopMode::Alternate | LoopMode::AlternateTimes(_)
+            ) {
+                motion.current = motion.sequence_origin();
+            }
+            next = sequence.begin_step();
+        }
+
+        let Some(step) = next else {
             // Sequence is complete
             // Execute completion callback safely without requiring ownership
             sequence.execute_completion();
@@ -213,27 +211,100 @@ impl<T: Animatable + Send + 'static> AnimationState<T> {
             motion.sequence = None;
             motion.keyframe_animation = None;
             *self = Self::Idle;
+            return None;
+        };
+
+        // Successfully advanced to next step
+        let target = sequence.current_step_target(motion.current, motion.sequence_origin());
+        let config = (*step.config).clone();
+        let mode = config.mode.clone();
+
+        // Update motion for new step
+        let velocity = match mode {
+            AnimationMode::Spring(spring) => spring.velocity_towards(motion.current, target),
+            AnimationMode::Decay(decay) => decay.velocity_towards(motion.current, target),
+            AnimationMode::Tween(_) => T::zero(),
+        };
+        motion.initial = motion.current;
+        motion.target = match mode {
+            AnimationMode::Decay(decay) => motion.current + decay.resting_offset(velocity),
+            _ => target,
+        };
+        motion.running = true;
+        motion.elapsed = Duration::default();
+        motion.delay_elapsed = Duration::default();
+        motion.velocity = velocity;
+        motion.initial_velocity = velocity;
+        motion.current_loop = 0;
+
+        // Update config handle for new step
+        global::modify_config(config_handle, |pooled_config| {
+            *pooled_config = config;
+        });
+
+        Some(mode)
+    }
+
+    /// Restarts the finished step of a sequence while its own loop mode has iterations left
+    ///
+    /// Unlike [`Self::handle_completion`] this never ends the sequence; once the step's loops
+    /// are done it returns `false` so the sequence can move on from the current value.
+    fn repeat_sequence_step(motion: &mut crate::Motion<T>, config: &AnimationConfig) -> bool {
+        let (iterations, alternate) = match config.loop_mode.unwrap_or(LoopMode::None) {
+            LoopMode::None => return false,
+            LoopMode::Infinite => (None, false),
+            LoopMode::Times(count) => (Some(u64::from(count)), false),
+            LoopMode::Alternate => (None, true),
+            LoopMode::AlternateTimes(count) => (Some(u64::from(count) * 2), true),
+        };
+
+        motion.current_loop = motion.current_loop.saturating_add(1);
+        if iterations.is_some_and(|count| u64::from(motion.current_loop) >= count) {
+            motion.current_loop = 0;
+            return false;
         }
 
-        None
+        if alternate {
+            std::mem::swap(&mut motion.initial, &mut motion.target);
+        }
+        motion.current = motion.initial;
+        motion.elapsed = Duration::default();
+        motion.velocity = T::zero();
+        motion.initial_velocity = Self::restart_velocity(motion, &config.mode, alternate);
+        motion.running = true;
+        true
+    }
+
+    /// Gets the velocity a loop iteration restarts with, once `motion.initial` and
+    /// `motion.target` are set for it
+    ///
+    /// Velocity inherited from an interrupted animation only pushes the first iteration.
+    /// Springs start every iteration with their own [`Spring::velocity`], and decays replay
+    /// their fling, mirrored when the loop `turned` around so they come to rest where the
+    /// previous iteration started.
+    fn restart_velocity(motion: &crate::Motion<T>, mode: &AnimationMode, turned: bool) -> T {
+        match mode {
+            AnimationMode::Spring(spring) => spring.velocity_towards(motion.initial, motion.target),
+            AnimationMode::Decay(_) if turned => motion.initial_velocity * -1.0,
+            AnimationMode::Decay(_) => motion.initial_velocity,
+            AnimationMode::Tween(_) => T::zero(),
+        }
     }
 
     /// Updates a sequence animation
     fn update_sequence(
         &mut self,
         sequence: Arc<AnimationSequence<T>>,
-        _config_handle: &ConfigHandle,
+        config_handle: &ConfigHandle,
         dt: f32,
         motion: &mut crate::Motion<T>,
     ) -> bool {
         if !motion.running {
-            if let Some(new_mode) =
-                self.advance_sequence_step(&sequence, &global::get_config(), motion)
-            {
+            if let Some(new_mode) = self.advance_sequence_step(&sequence, config_handle, motion) {
                 // Successfully advanced to next step
                 *self = Self::Running {
                     mode: new_mode,
-                    config_handle: global::get_config(),
+                    config_handle: config_handle.clone(),
                 };
                 return true;
             } else {
@@ -242,79 +313,109 @@ impl<T: Animatable + Send + 'static> AnimationState<T> {
             }
         }
 
-        // If we're here, the current step is still running
-        // Delegate to running update
-        if let Self::Running {
-            mode,
-            config_handle,
-        } = self
-        {
-            let mode = *mode;
-            let config_handle = config_handle.clone();
-            self.update_running(mode, &config_handle, dt, motion)
-        } else {
-            // This shouldn't happen, but handle gracefully
-            false
-        }
+        // The current step has already been primed by `animate_to`,
+        // so hand over to the running state and let it drive the step
+        let mode = sequence
+            .current_config()
+            .map_or_else(AnimationMode::default, |config| config.mode.clone());
+        *self = Self::Running {
+            mode: mode.clone(),
+            config_handle: config_handle.clone(),
+        };
+        self.update_running(mode, config_handle, dt, motion)
     }
 
     /// Updates a keyframe animation
     fn update_keyframes(
         &mut self,
-        animation: Arc<KeyframeAnimation<T>>,
+        animation: Arc<KeyframeTrack<T>>,
         config_handle: &ConfigHandle,
         dt: f32,
         motion: &mut crate::Motion<T>,
     ) -> bool {
-        let progress =
-            (motion.elapsed.as_secs_f32() / animation.duration.as_secs_f32()).clamp(0.0, 1.0);
+        let config = global::get_config_ref(config_handle).unwrap_or_default();
+        let dt = dt * config.playback_rate();
+
+        if motion.delay_elapsed < config.delay {
+            motion.delay_elapsed = advance_elapsed(motion.delay_elapsed, dt.abs());
+            return true;
+        }
+
+        if dt < 0.0 && motion.elapsed.is_zero() {
+            // Reversed playback starts from the end of the animation
+            motion.elapsed = animation.duration();
+        }
+        motion.elapsed = advance_elapsed(motion.elapsed, dt);
+        let progress = keyframe_progress(&animation, motion.elapsed);
 
-        let (start, end) = if animation.keyframes.is_empty() {
+        let Some(value) = animation.sample_directed(progress, motion.reverse) else {
             // No keyframes, nothing to animate
             return false;
-        } else {
-            animation
-                .keyframes
-                .windows(2)
-                .find(|w| progress >= w[0].offset && progress <= w[1].offset)
-                .map(|w| (&w[0], &w[1]))
-                .unwrap_or_else(|| {
-                    if progress <= animation.keyframes[0].offset {
-                        let first = &animation.keyframes[0];
-                        (first, first)
-                    } else {
-                        let last = animation
-                            .keyframes
-                            .last()
-                            .expect("Keyframes vector should not be empty here");
-                        (last, last)
-                    }
-                })
         };
+        motion.current = value;
 
-        let local_progress = if start.offset == end.offset {
-            1.0
+        let completed = if dt < 0.0 {
+            progress <= 0.0
         } else {
-            (progress - start.offset) / (end.offset - start.offset)
+            progress >= 1.0
         };
+        if completed {
+            self.handle_keyframe_completion(motion, &config)
+        } else {
+            true
+        }
+    }
 
-        let eased_progress = end
-            .easing
-            .map_or(local_progress, |ease| (ease)(local_progress, 0.0, 1.0, 1.0));
+    /// Handles the end of a keyframe iteration, restarting it while loops remain
+    ///
+    /// Keyframes resample their own values, so unlike [`Self::handle_completion`] this only
+    /// rewinds time and flips the direction for alternating loops.
+    fn handle_keyframe_completion(
+        &mut self,
+        motion: &mut crate::Motion<T>,
+        config: &AnimationConfig,
+    ) -> bool {
+        let (iterations, alternate) = match config.loop_mode.unwrap_or(LoopMode::None) {
+            LoopMode::None => (Some(1), false),
+            LoopMode::Infinite => (None, false),
+            LoopMode::Times(count) => (Some(u64::from(count)), false),
+            LoopMode::Alternate => (None, true),
+            LoopMode::AlternateTimes(count) => (Some(u64::from(count) * 2), true),
+        };
 
-        motion.current = start.value.interpolate(&end.value, eased_progress);
-        motion.elapsed += Duration::from_secs_f32(dt);
+        motion.current_loop = motion.current_loop.saturating_add(1);
+        if iterations.is_some_and(|count| u64::from(motion.current_loop) >= count) {
+            motion.running = false;
+            motion.current_loop = 0;
+            motion.velocity = T::zero();
+            motion.keyframe_animation = None;
+            *self = Self::Idle;
+            if let Some(ref f) = config.on_complete
+                && let Ok(mut guard) = f.lock()
+            {
+                guard();
+            }
+            return false;
+        }
 
-        if progress >= 1.0 {
-            let config = global::get_config_ref(config_handle).unwrap_or_default();
-            self.handle_completion(motion, &config)
-        } else {
-            true
+        if alternate {
+            motion.reverse = !motion.reverse;
         }
+        // Negative playback rates move this back to the end on the next frame
+        motion.elapsed = Duration::default();
+        motion.velocity = T::zero();
+        motion.running = true;
+        true
     }
 
     /// Updates spring animation using optimized integration
     fn update_spring(&self, motion: &mut crate::Motion<T>, spring: Spring, dt: f32) -> SpringState {
+        if spring.solver == SpringSolver::Analytic {
+            motion.elapsed = advance_elapsed(motion.elapsed, dt);
+            Self::apply_spring(motion, &spring);
+            return self.check_spring_completion(motion);
+        }
+
         let epsilon = motion.get_epsilon();
 
         // Check for completion first
@@ -379,6 +480,56 @@ impl<T: Animatable + Send + 'static> AnimationState<T> {
         self.check_spring_completion(motion)
     }
 
+    /// Positions an analytic spring at `motion.elapsed` from its closed-form solution
+    fn apply_spring(motion: &mut crate::Motion<T>, spring: &Spring) {
+        let (displacement, velocity) = spring
+            .response(motion.elapsed.as_secs_f32())
+            .apply(motion.initial - motion.target, motion.initial_velocity);
+        motion.current = motion.target + displacement;
+        motion.velocity = velocity;
+    }
+
+    /// Updates a decay animation, handing off to its boundary spring on overshoot
+    ///
+    /// Returns `true` once the value has come to rest.
+    fn update_decay(
+        &mut self,
+        motion: &mut crate::Motion<T>,
+        decay: Decay,
+        config_handle: &ConfigHandle,
+        dt: f32,
+    ) -> bool {
+        motion.elapsed = advance_elapsed(motion.elapsed, dt);
+        let (offset, velocity) =
+            decay.offset_at(motion.initial_velocity, motion.elapsed.as_secs_f32());
+        motion.current = motion.initial + offset;
+        motion.velocity = velocity;
+
+        if let Some((min, max)) = motion.decay_bounds() {
+            let clamped = motion.current.clamp_between(&min, &max);
+            if (motion.current - clamped).magnitude() > 0.0 {
+                // Overshot a bound: the boundary spring pulls the value back onto it, while
+                // components still within bounds head for their resting point
+                motion.initial = motion.current;
+                motion.target = motion.target.clamp_between(&min, &max);
+                motion.initial_velocity = velocity;
+                motion.elapsed = Duration::default();
+                *self = Self::Running {
+                    mode: AnimationMode::Spring(decay.boundary_spring),
+                    config_handle: config_handle.clone(),
+                };
+                return false;
+            }
+        }
+
+        if (motion.target - motion.current).magnitude() < motion.get_epsilon() {
+            motion.current = motion.target;
+            motion.velocity = T::zero();
+            return true;
+        }
+        false
+    }
+
     /// Checks if spring animation is complete
     fn check_spring_completion(&self, motion: &mut crate::Motion<T>) -> SpringState {
         let epsilon = motion.get_epsilon();
@@ -398,32 +549,236 @@ impl<T: Animatable + Send + 'static> AnimationState<T> {
 
     /// Updates tween animation
     fn update_tween(&self, motion: &mut crate::Motion<T>, tween: Tween, dt: f32) -> bool {
-        let elapsed_secs = motion.elapsed.as_secs_f32() + dt;
-        motion.elapsed = Duration::from_secs_f32(elapsed_secs);
+        if dt < 0.0 && motion.elapsed.is_zero() {
+            // Reversed playback starts from the end of the tween
+            motion.elapsed = tween.duration;
+        }
+        motion.elapsed = advance_elapsed(motion.elapsed, dt);
+
+        // Track velocity so an interrupting animation can inherit it
+        let previous = motion.current;
+        let progress = Self::apply_tween(motion, &tween);
+        if dt != 0.0 {
+            motion.velocity = (motion.current - previous) * (1.0 / dt.abs());
+        }
+
+        if dt < 0.0 {
+            progress <= 0.0 || tween.duration.is_zero()
+        } else {
+            progress >= 1.0
+        }
+    }
+
+    /// Positions a tween at `motion.elapsed` and returns its linear progress
+    fn apply_tween(motion: &mut crate::Motion<T>, tween: &Tween) -> f32 {
         let duration_secs = tween.duration.as_secs_f32();
 
         let progress = if duration_secs == 0.0 {
             1.0
         } else {
-            (elapsed_secs * (1.0 / duration_secs)).min(1.0)
+            (motion.elapsed.as_secs_f32() * (1.0 / duration_secs)).min(1.0)
         };
 
         if progress <= 0.0 {
             motion.current = motion.initial;
-            return false;
+            return progress;
         } else if progress >= 1.0 {
             motion.current = motion.target;
-            return true;
+            return progress;
         }
 
-        let eased_progress = (tween.easing)(progress, 0.0, 1.0, 1.0);
+        let eased_progress = tween.easing.ease(progress);
         match eased_progress {
             0.0 => motion.current = motion.initial,
             1.0 => motion.current = motion.target,
             _ => motion.current = motion.initial.interpolate(&motion.target, eased_progress),
         }
 
-        progress >= 1.0
+        // Blend out of an inherited velocity: v0·T·s·(1 - s)² starts with slope v0 and
+        // vanishes together with its slope at the end, keeping the motion C1-continuous
+        if motion.initial_velocity.magnitude() > 0.0 {
+            let remaining = 1.0 - progress;
+            let blend = duration_secs * progress * remaining * remaining;
+            motion.current = motion.current + motion.initial_velocity * blend;
+        }
+
+        progress
+    }
+
+    /// Gets the length of the active animation's seekable timeline, delays included
+    ///
+    /// Analytic springs last until they settle. Returns `None` for animations without a
+    /// fixed length (numerically integrated springs and decays).
+    pub fn seekable_duration(&self, motion: &crate::Motion<T>) -> Option<Duration> {
+        if let Some(sequence) = motion.sequence.as_ref() {
+            if sequence.is_looping() {
+                return None;
+            }
+            let mut from = motion.sequence_origin();
+            return sequence
+                .steps()
+                .iter()
+                .try_fold(Duration::ZERO, |total, step| {
+                    let to = step.target_from(from);
+                    let length = Self::seekable_length(&step.config, from, to, T::zero())?;
+                    from = to;
+                    Some(total + length)
+                });
+        }
+
+        match self {
+            Self::Idle | Self::Sequence { .. } => None,
+            Self::Running { config_handle, .. } => {
+                let config = global::get_config_ref(config_handle).unwrap_or_default();
+                Self::seekable_length(
+                    &config,
+                    motion.initial,
+                    motion.target,
+                    motion.initial_velocity,
+                )
+            }
+            Self::Keyframes {
+                animation,
+                config_handle,
+            } => {
+                let config = global::get_config_ref(config_handle).unwrap_or_default();
+                Some(config.delay + animation.duration())
+            }
+        }
+    }
+
+    /// Gets the seekable length of one animation from `from` to `to`, delay included
+    fn seekable_length(config: &AnimationConfig, from: T, to: T, velocity: T) -> Option<Duration> {
+        let length = match &config.mode {
+            AnimationMode::Tween(tween) => tween.duration,
+            AnimationMode::Spring(spring) if spring.solver == SpringSolver::Analytic => {
+                let epsilon = config.epsilon.unwrap_or_else(T::epsilon);
+                let distance = (from - to).magnitude();
+                let speed = velocity.magnitude();
+                // The direction of the velocity is unknown here, so take the slower case
+                spring
+                    .settling_time(distance, speed, epsilon)
+                    .max(spring.settling_time(distance, -speed, epsilon))
+            }
+            AnimationMode::Spring(_) | AnimationMode::Decay(_) => return None,
+        };
+        Some(config.delay + length)
+    }
+
+    /// Jumps the active time-based animation to `time` since its start and renders that frame
+    ///
+    /// Delays are part of the timeline. Returns `false` if nothing seekable is active.
+    pub fn seek(&mut self, time: Duration, motion: &mut crate::Motion<T>) -> bool {
+        let Some(config_handle) = self.config_handle().cloned() else {
+            return false;
+        };
+
+        if let Some(sequence) = motion.sequence.clone() {
+            return self.seek_sequence(&sequence, &config_handle, time, motion);
+        }
+
+        match self {
+            Self::Idle | Self::Sequence { .. } => false,
+            Self::Running { mode, .. } => {
+                let config = global::get_config_ref(&config_handle).unwrap_or_default();
+                Self::seek_mode(motion, mode.clone(), config.delay, time)
+            }
+            Self::Keyframes { animation, .. } => {
+                let config = global::get_config_ref(&config_handle).unwrap_or_default();
+                let delay = config.delay;
+                motion.delay_elapsed = time.min(delay);
+                motion.elapsed = time.saturating_sub(delay).min(animation.duration());
+                motion.velocity = T::zero();
+                let progress = keyframe_progress(animation, motion.elapsed);
+                if let Some(value) = animation.sample_directed(progress, motion.reverse) {
+                    motion.current = value;
+                }
+                true
+            }
+        }
+    }
+
+    /// Positions a tween or analytic spring `time` after its start, delay included
+    ///
+    /// Returns `false` for numerically integrated springs and decays, which cannot be seeked.
+    fn seek_mode(
+        motion: &mut crate::Motion<T>,
+        mode: AnimationMode,
+        delay: Duration,
+        time: Duration,
+    ) -> bool {
+        motion.delay_elapsed = time.min(delay);
+        let elapsed = time.saturating_sub(delay);
+
+        match mode {
+            AnimationMode::Tween(tween) => {
+                motion.elapsed = elapsed.min(tween.duration);
+                motion.velocity = T::zero();
+                Self::apply_tween(motion, &tween);
+                true
+            }
+            AnimationMode::Spring(spring) if spring.solver == SpringSolver::Analytic => {
+                motion.elapsed = elapsed;
+                Self::apply_spring(motion, &spring);
+                true
+            }
+            AnimationMode::Spring(_) | AnimationMode::Decay(_) => false,
+        }
+    }
+
+    /// Seeks within a sequence of tweens and analytic springs, switching to the step that
+    /// covers `time`
+    fn seek_sequence(
+        &mut self,
+        sequence: &Arc<AnimationSequence<T>>,
+        config_handle: &ConfigHandle,
+        time: Duration,
+        motion: &mut crate::Motion<T>,
+    ) -> bool {
+        if sequence.is_looping() {
+            return false;
+        }
+        // Time runs forwards, whichever way the sequence was playing
+        sequence.set_reversed(false);
+
+        let last_index = sequence.total_steps().saturating_sub(1);
+        let mut from = motion.sequence_origin();
+        let mut step_start = Duration::ZERO;
+
+        for (index, step) in sequence.steps().iter().enumerate() {
+            let to = step.target_from(from);
+            let Some(step_length) = Self::seekable_length(&step.config, from, to, T::zero()) else {
+                return false;
+            };
+
+            if time < step_start + step_length || index == last_index {
+                sequence.jump_to_step(index);
+                motion.initial = from;
+                motion.target = to;
+                motion.initial_velocity = T::zero();
+                motion.running = true;
+                global::modify_config(config_handle, |pooled_config| {
+                    *pooled_config = (*step.config).clone();
+                });
+                Self::seek_mode(
+                    motion,
+                    step.config.mode.clone(),
+                    step.config.delay,
+                    time - step_start,
+                );
+
+                *self = Self::Running {
+                    mode: step.config.mode.clone(),
+                    config_handle: config_handle.clone(),
+                };
+                return true;
+            }
+
+            from = to;
+            step_start += step_length;
+        }
+
+        false
     }
 
     /// Handles animation completion and loop logic
@@ -442,11 +797,12 @@ impl<T: Animatable + Send + 'static> AnimationState<T> {
                 motion.current = motion.initial;
                 motion.elapsed = Duration::default();
                 motion.velocity = T::zero();
+                motion.initial_velocity = Self::restart_velocity(motion, &config.mode, false);
                 motion.running = true; // Ensure animation continues running
                 true
             }
             LoopMode::Times(count) => {
-                motion.current_loop += 1;
+                motion.current_loop = motion.current_loop.saturating_add(1);
                 if motion.current_loop >= count {
                     motion.running = false;
                     motion.current_loop = 0;
@@ -459,6 +815,7 @@ impl<T: Animatable + Send + 'static> AnimationState<T> {
                     motion.current = motion.initial;
                     motion.elapsed = Duration::default();
                     motion.velocity = T::zero();
+                    motion.initial_velocity = Self::restart_velocity(motion, &config.mode, false);
                     motion.running = true; // Ensure animation continues running
                     true
                 }
@@ -471,12 +828,13 @@ impl<T: Animatable + Send + 'static> AnimationState<T> {
                 motion.current = motion.initial;
                 motion.elapsed = Duration::default();
                 motion.velocity = T::zero();
+                motion.initial_velocity = Self::restart_velocity(motion, &config.mode, true);
                 motion.running = true; // Ensure animation continues running
                 true
             }
             LoopMode::AlternateTimes(count) => {
-                motion.current_loop += 1;
-                if motion.current_loop >= count * 2 {
+                motion.current_loop = motion.current_loop.saturating_add(1);
+                if u64::from(motion.current_loop) >= u64::from(count) * 2 {
                     motion.running = false;
                     motion.current_loop = 0;
                     motion.velocity = T::zero();
@@ -492,6 +850,7 @@ impl<T: Animatable + Send + 'static> AnimationState<T> {
                     motion.current = motion.initial;
                     motion.elapsed = Duration::default();
                     motion.velocity = T::zero();
+                    motion.initial_velocity = Self::restart_velocity(motion, &config.mode, true);
                     motion.running = true; // Ensure animation continues running
                     true
                 }
@@ -538,6 +897,28 @@ impl<T: Animatable + Send + 'static> AnimationState<T> {
     }
 }
 
+/// Gets how far through one iteration of `animation` `elapsed` is, from 0.0 to 1.0
+fn keyframe_progress<T: Animatable>(animation: &KeyframeTrack<T>, elapsed: Duration) -> f32 {
+    if animation.duration().is_zero() {
+        return 1.0;
+    }
+    (elapsed.as_secs_f32() / animation.duration().as_secs_f32()).clamp(0.0, 1.0)
+}
+
+/// Moves an elapsed time by a signed frame delta, saturating at zero and `Duration::MAX`
+fn advance_elapsed(elapsed: Duration, dt: f32) -> Duration {
+    let step = Duration::try_from_secs_f32(dt.abs()).unwrap_or(if dt.is_nan() {
+        Duration::ZERO
+    } else {
+        Duration::MAX
+    });
+    if dt < 0.0 {
+        elapsed.saturating_sub(step)
+    } else {
+        elapsed.saturating_add(step)
+    }
+}
+
 impl<T: Animatable> Default for AnimationState<T> {
     fn default() -> Self {
         Self::Idle
@@ -551,10 +932,12 @@ mod tests {
     use super::*;
     use crate::Motion;
     use crate::animations::core::AnimationMode;
+    use crate::animations::easing::CubicBezier;
     use crate::animations::spring::Spring;
     use crate::keyframes::KeyframeAnimation;
-    use crate::prelude::Tween;
+    use crate::prelude::{LoopMode, Tween};
     use crate::sequence::{AnimationSequence, AnimationStep};
+    use crate::test_utils::{FRAME, linear_tween, play, recorded};
     use std::sync::{Arc, Mutex};
 
     #[test]
@@ -593,16 +976,8 @@ mod tests {
     #[test]
     fn test_animation_state_sequence() {
         let steps = vec![
-            AnimationStep {
-                target: 10.0f32,
-                config: Arc::new(AnimationConfig::default()),
-                predicted_next: None,
-            },
-            AnimationStep {
-                target: 20.0f32,
-                config: Arc::new(AnimationConfig::default()),
-                predicted_next: None,
-            },
+            AnimationStep::new(10.0f32, AnimationConfig::default()),
+            AnimationStep::new(20.0f32, AnimationConfig::default()),
         ];
 
         let sequence = Arc::new(AnimationSequence::from_steps(steps));
@@ -631,8 +1006,10 @@ mod tests {
         animation = animation.add_keyframe(100.0f32, 1.0, None).unwrap();
 
         let config_handle = global::get_config();
-        let state =
-            AnimationState::<f32>::new_keyframes(Arc::new(animation), config_handle.clone());
+        let state = AnimationState::<f32>::new_keyframes(
+            Arc::new(animation.track()),
+            config_handle.clone(),
+        );
         assert!(state.is_active());
 
         let mut motion = Motion::new(0.0f32);
@@ -782,6 +1159,89 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_loop_restarts_drop_inherited_velocity() {
+        let tween =
+            || AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(125))));
+        for loop_mode in [
+            LoopMode::Infinite,
+            LoopMode::Times(3),
+            LoopMode::Alternate,
+            LoopMode::AlternateTimes(2),
+        ] {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to_with_velocity(100.0, tween().with_loop(loop_mode), 2000.0);
+            for _ in 0..8 {
+                motion.update(1.0 / 64.0);
+            }
+            assert_eq!(motion.initial_velocity, 0.0, "{loop_mode:?}");
+
+            // The next iteration follows the plain tween without the inherited push
+            motion.update(1.0 / 64.0);
+            let expected = motion.initial.interpolate(&motion.target, 0.125);
+            assert!((motion.current - expected).abs() < 0.01, "{loop_mode:?}");
+        }
+    }
+
+    #[test]
+    fn test_looped_decays_replay_their_fling() {
+        let decay = || AnimationConfig::new(AnimationMode::Decay(Decay::default()));
+        let run = |motion: &mut Motion<f32>| {
+            let mut frames = 0;
+            while motion.is_running() && frames < 10_000 {
+                motion.update(1.0 / 64.0);
+                frames += 1;
+            }
+            frames
+        };
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_decay(1000.0, decay().with_loop(LoopMode::Times(2)));
+        let rest = motion.target;
+        assert!(run(&mut motion) < 10_000);
+        assert_eq!(motion.current, rest);
+
+        // Alternating passes fling back to where they started
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_decay(1000.0, decay().with_loop(LoopMode::AlternateTimes(1)));
+        assert!(run(&mut motion) < 10_000);
+        assert_eq!(motion.current, 0.0);
+
+        // A looping decay step inside a sequence moves on too
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(
+                    100.0,
+                    AnimationConfig::new(AnimationMode::Decay(
+                        Decay::default().with_velocity(500.0),
+                    ))
+                    .with_loop(LoopMode::Times(2)),
+                )
+                .then(0.0, linear_tween(125)),
+        );
+        assert!(run(&mut motion) < 10_000);
+        assert_eq!(motion.current, 0.0);
+    }
+
+    #[test]
+    fn test_loop_restarts_keep_the_springs_own_velocity() {
+        let spring = Spring {
+            velocity: 500.0,
+            ..Spring::default()
+        };
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(spring.analytic()))
+                .with_loop(LoopMode::Times(2)),
+        );
+        while motion.current_loop == 0 && motion.is_running() {
+            motion.update(1.0 / 64.0);
+        }
+        assert_eq!(motion.initial_velocity, 500.0);
+    }
+
     #[test]
     fn test_loop_mode_alternate() {
         use crate::Motion;
@@ -1039,4 +1499,274 @@ mod tests {
 
         global::return_config(config_handle);
     }
+
+    #[test]
+    fn test_sequence_loop_times_restarts_from_origin() {
+        let (sequence, log) = recorded(
+            AnimationSequence::new()
+                .then(100.0, linear_tween(125))
+                .then(50.0, linear_tween(125))
+                .with_loop(LoopMode::Times(2)),
+        );
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(sequence);
+        let values = play(&mut motion);
+
+        assert_eq!(values.len(), 32);
+        assert_eq!(values[7], 100.0);
+        // The second pass jumps back to where the sequence started
+        assert_eq!(values[15], 0.0);
+        assert_eq!(values[23], 100.0);
+        assert_eq!(values[31], 50.0);
+        assert_eq!(log.events().iter().filter(|e| *e == "start 0").count(), 2);
+        assert_eq!(log.events().last().map(String::as_str), Some("done"));
+        assert_eq!(log.events().iter().filter(|e| *e == "done").count(), 1);
+    }
+
+    #[test]
+    fn test_sequence_alternate_plays_steps_back_to_origin() {
+        let (sequence, log) = recorded(
+            AnimationSequence::new()
+                .then(100.0, linear_tween(125))
+                .then(50.0, linear_tween(125))
+                .with_loop(LoopMode::AlternateTimes(1)),
+        );
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(sequence);
+        let values = play(&mut motion);
+
+        assert_eq!(values.len(), 32);
+        assert_eq!(values[7], 100.0);
+        assert_eq!(values[15], 50.0);
+        // The last step plays backwards first, then the first one back to the origin
+        assert!((values[19] - 75.0).abs() < 0.01);
+        assert_eq!(values[23], 100.0);
+        assert!((values[27] - 50.0).abs() < 0.01);
+        assert_eq!(values[31], 0.0);
+        assert_eq!(
+            log.events(),
+            [
+                "start 0",
+                "complete 0",
+                "start 1",
+                "complete 1",
+                "start 1",
+                "complete 1",
+                "start 0",
+                "complete 0",
+                "done",
+            ]
+            .map(String::from)
+        );
+        assert!(motion.sequence.is_none());
+    }
+
+    #[test]
+    fn test_sequence_infinite_alternate_keeps_turning_around() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(100.0, linear_tween(125))
+                .with_loop(LoopMode::Alternate),
+        );
+        let values: Vec<f32> = (0..64)
+            .map(|_| {
+                motion.update(FRAME);
+                motion.current
+            })
+            .collect();
+        assert!(motion.is_running());
+        assert_eq!(values[7], 100.0);
+        assert_eq!(values[15], 0.0);
+        assert_eq!(values[63], 0.0);
+        // Eight passes in, it is about to head out again
+        assert!(!motion.sequence.as_ref().unwrap().is_reversed());
+    }
+
+    #[test]
+    fn test_step_loops_repeat_inside_a_sequence() {
+        let (sequence, log) = recorded(
+            AnimationSequence::new()
+                .then(100.0, linear_tween(125).with_loop(LoopMode::Times(2)))
+                .then(50.0, linear_tween(125)),
+        );
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(sequence);
+        let values = play(&mut motion);
+
+        // The first step plays twice before the sequence moves on
+        assert_eq!(values.len(), 24);
+        assert_eq!(values[7], 0.0);
+        assert_eq!(values[15], 100.0);
+        assert!((values[19] - 75.0).abs() < 0.01);
+        assert_eq!(values[23], 50.0);
+        assert_eq!(
+            log.events(),
+            ["start 0", "complete 0", "start 1", "complete 1", "done"].map(String::from)
+        );
+        assert_eq!(motion.current_loop, 0);
+
+        // An alternating step returns to where it started, the next step carries on from there
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(
+                    100.0,
+                    linear_tween(125).with_loop(LoopMode::AlternateTimes(1)),
+                )
+                .then(50.0, linear_tween(125)),
+        );
+        let values = play(&mut motion);
+        assert_eq!(values.len(), 24);
+        assert_eq!(values[7], 100.0);
+        assert!((values[11] - 50.0).abs() < 0.01);
+        assert_eq!(values[15], 0.0);
+        assert!((values[19] - 25.0).abs() < 0.01);
+        assert_eq!(values[23], 50.0);
+    }
+
+    #[test]
+    fn test_step_loops_drop_inherited_velocity() {
+        let spring = AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(-100.0, linear_tween(125));
+        for _ in 0..4 {
+            motion.update(FRAME);
+        }
+
+        // The first step takes over the speed of the interrupted tween
+        motion.animate_sequence(
+            AnimationSequence::new().then(100.0, spring.with_loop(LoopMode::Times(2))),
+        );
+        assert!(motion.initial_velocity < 0.0);
+
+        while motion.current_loop == 0 && motion.is_running() {
+            motion.update(FRAME);
+        }
+        assert_eq!(motion.current_loop, 1);
+        assert_eq!(motion.initial_velocity, 0.0);
+    }
+
+    #[test]
+    fn test_step_loops_repeat_on_reversed_passes() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(100.0, linear_tween(125).with_loop(LoopMode::Times(2)))
+                .then(50.0, linear_tween(125))
+                .with_loop(LoopMode::AlternateTimes(1)),
+        );
+        let values = play(&mut motion);
+
+        assert_eq!(values.len(), 48);
+        assert_eq!(values[23], 50.0);
+        assert_eq!(values[31], 100.0);
+        // Reversed, the looping step heads back to the origin twice
+        assert!((values[35] - 50.0).abs() < 0.01);
+        assert_eq!(values[39], 100.0);
+        assert!((values[43] - 50.0).abs() < 0.01);
+        assert_eq!(values[47], 0.0);
+    }
+
+    #[test]
+    fn test_reverse_playing_sequence() {
+        let (sequence, log) = recorded(
+            AnimationSequence::new()
+                .then(100.0, linear_tween(125))
+                .then(200.0, linear_tween(125)),
+        );
+        let mut motion = Motion::new(0.0f32);
+        assert!(!motion.reverse_sequence());
+        motion.animate_sequence(sequence);
+        for _ in 0..12 {
+            motion.update(FRAME);
+        }
+        assert!((motion.current - 150.0).abs() < 0.01);
+
+        // The current step retraces its way back to where it started
+        assert!(motion.reverse_sequence());
+        let values = play(&mut motion);
+        assert_eq!(values.len(), 12);
+        assert!((values[1] - 125.0).abs() < 0.01);
+        assert_eq!(values[3], 100.0);
+        assert!((values[7] - 50.0).abs() < 0.01);
+        assert_eq!(values[11], 0.0);
+        assert_eq!(
+            log.events(),
+            [
+                "start 0",
+                "complete 0",
+                "start 1",
+                "complete 1",
+                "start 0",
+                "complete 0",
+                "done"
+            ]
+            .map(String::from)
+        );
+        assert!(!motion.reverse_sequence());
+    }
+
+    #[test]
+    fn test_reversed_eased_step_heads_back_without_jumping() {
+        let ease_in = Tween::new(Duration::from_millis(125)).with_curve(CubicBezier::EASE_IN);
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(100.0, AnimationConfig::new(AnimationMode::Tween(ease_in))),
+        );
+        for _ in 0..4 {
+            motion.update(FRAME);
+        }
+        let turned_at = motion.current;
+        assert!((turned_at - 31.54).abs() < 0.01);
+
+        assert!(motion.reverse_sequence());
+        let values = play(&mut motion);
+        assert_eq!(values.len(), 4);
+        assert!(
+            (turned_at - values[0]).abs() < 5.0,
+            "jumped from {turned_at} to {}",
+            values[0]
+        );
+        assert!(values.windows(2).all(|pair| pair[1] <= pair[0]));
+        assert_eq!(values[3], 0.0);
+    }
+
+    #[test]
+    fn test_reverse_twice_plays_forwards_again() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(100.0, linear_tween(125))
+                .then(200.0, linear_tween(125)),
+        );
+        for _ in 0..4 {
+            motion.update(FRAME);
+        }
+        assert!(motion.reverse_sequence());
+        motion.update(FRAME);
+        assert!(motion.current < 50.0);
+        assert!(motion.reverse_sequence());
+        let values = play(&mut motion);
+        assert_eq!(*values.last().unwrap(), 200.0);
+    }
+
+    #[test]
+    fn test_looping_sequences_are_not_seekable() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(100.0, linear_tween(125))
+                .with_loop(LoopMode::Times(2)),
+        );
+        assert!(!motion.seek_time(Duration::from_millis(50)));
+
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(100.0, linear_tween(125).with_loop(LoopMode::Infinite))
+                .then(0.0, linear_tween(125)),
+        );
+        assert!(!motion.seek(0.5));
+    }
 }
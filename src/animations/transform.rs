@@ -108,6 +108,10 @@ impl Animatable for f32 {
         self.abs()
     }
 
+    fn clamp_between(&self, min: &Self, max: &Self) -> Self {
+        self.max(*min).min(*max)
+    }
+
     // Uses default epsilon of 0.01 from the trait
 }
 
@@ -148,6 +152,15 @@ impl Animatable for Transform {
         Transform::new(0.0, 0.0, 0.0, 0.0)
     }
 
+    fn clamp_between(&self, min: &Self, max: &Self) -> Self {
+        Transform::new(
+            self.x.clamp_between(&min.x, &max.x),
+            self.y.clamp_between(&min.y, &max.y),
+            self.scale.clamp_between(&min.scale, &max.scale),
+            self.rotation.clamp_between(&min.rotation, &max.rotation),
+        )
+    }
+
     // Uses default epsilon of 0.01 from the trait - no need for TRANSFORM_EPSILON
 }
 
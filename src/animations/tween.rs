@@ -3,7 +3,8 @@
 //! Provides time-based animation with customizable easing functions.
 //! Supports duration and interpolation control for smooth animations.
 
-use easer::functions::{Easing, Linear};
+use crate::animations::easing::{Easing, EasingFn};
+use crate::animations::spring::Spring;
 pub use instant::Duration;
 
 /// Configuration for tween-based animations
@@ -16,12 +17,12 @@ pub use instant::Duration;
 /// let tween = Tween::new(Duration::from_secs(1))
 ///     .with_easing(easer::functions::Cubic::ease_in_out);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Tween {
     /// Duration of the animation
     pub duration: Duration,
-    /// Easing function for interpolation
-    pub easing: fn(f32, f32, f32, f32) -> f32,
+    /// Easing curve for interpolation
+    pub easing: Easing,
 }
 
 /// Default tween configuration with 300ms duration and linear easing
@@ -29,7 +30,7 @@ impl Default for Tween {
     fn default() -> Self {
         Self {
             duration: Duration::from_millis(300),
-            easing: Linear::ease_in_out,
+            easing: Easing::default(),
         }
     }
 }
@@ -39,7 +40,19 @@ impl Tween {
     pub fn new(duration: Duration) -> Self {
         Self {
             duration,
-            easing: Linear::ease_in_out,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Creates a tween that follows `spring` over the time the spring takes to settle
+    ///
+    /// Use [`Spring::easing`] with [`Tween::with_curve`] to fit spring motion into a
+    /// different duration.
+    pub fn from_spring(spring: Spring) -> Self {
+        let easing = spring.easing();
+        Self {
+            duration: easing.duration(),
+            easing: Easing::Spring(easing),
         }
     }
 
@@ -47,8 +60,14 @@ impl Tween {
     ///
     /// # Arguments
     /// * `easing` - Function that takes (t, b, c, d) and returns interpolated value
-    pub fn with_easing(mut self, easing: fn(f32, f32, f32, f32) -> f32) -> Self {
-        self.easing = easing;
+    pub fn with_easing(mut self, easing: EasingFn) -> Self {
+        self.easing = Easing::Function(easing);
+        self
+    }
+
+    /// Sets the easing curve, e.g. a [`CubicBezier`](crate::animations::easing::CubicBezier)
+    pub fn with_curve(mut self, easing: impl Into<Easing>) -> Self {
+        self.easing = easing.into();
         self
     }
 }
@@ -56,13 +75,14 @@ impl Tween {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use easer::functions::{Cubic, Easing};
+    use crate::animations::easing::CubicBezier;
+    use easer::functions::{Cubic, Easing as _, Linear};
 
     #[test]
     fn test_tween_new() {
         let tween = Tween {
             duration: Duration::from_secs(1),
-            easing: Cubic::ease_in_out,
+            easing: Easing::Function(Cubic::ease_in_out),
         };
 
         assert_eq!(tween.duration, Duration::from_secs(1));
@@ -72,20 +92,43 @@ mod tests {
     fn test_tween_interpolation() {
         let tween = Tween {
             duration: Duration::from_secs(1),
-            easing: Linear::ease_in_out,
+            easing: Easing::Function(Linear::ease_in_out),
         };
 
         // Test midpoint
         let progress = 0.5;
-        let result = (tween.easing)(progress, 0.0, 1.0, 1.0);
+        let result = tween.easing.ease(progress);
         assert!((result - 0.5).abs() < f32::EPSILON);
 
         // Test start
-        let result = (tween.easing)(0.0, 0.0, 1.0, 1.0);
+        let result = tween.easing.ease(0.0);
         assert!((result - 0.0).abs() < f32::EPSILON);
 
         // Test end
-        let result = (tween.easing)(1.0, 0.0, 1.0, 1.0);
+        let result = tween.easing.ease(1.0);
         assert!((result - 1.0).abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn test_tween_with_cubic_bezier() {
+        let curve = CubicBezier::new(0.2, 0.0, 0.0, 1.0);
+        let tween = Tween::new(Duration::from_secs(1)).with_curve(curve);
+        assert_eq!(tween.easing, Easing::CubicBezier(curve));
+        assert_eq!(tween.easing.ease(0.5), curve.ease(0.5));
+
+        let tween = tween.with_easing(Cubic::ease_in);
+        assert_eq!(tween.easing.ease(0.5), Cubic::ease_in(0.5, 0.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_tween_from_spring() {
+        let spring = Spring::snappy();
+        let tween = Tween::from_spring(spring);
+        assert_eq!(tween.duration, spring.easing().duration());
+        assert_eq!(tween.easing, Easing::Spring(spring.easing()));
+
+        // A fixed duration keeps the same curve
+        let fixed = Tween::new(Duration::from_millis(250)).with_curve(spring.easing());
+        assert_eq!(fixed.easing.ease(0.5), tween.easing.ease(0.5));
+    }
 }
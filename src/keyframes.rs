@@ -1,26 +1,79 @@
 use crate::Duration;
-use crate::animations::core::Animatable;
+use crate::animations::core::{Animatable, AnimationConfig, AnimationMode, LoopMode, OnComplete};
+use crate::animations::easing::{Easing, SpringEasing};
+use std::sync::{Arc, Mutex};
 use tracing::error;
 
-pub type EasingFn = fn(f32, f32, f32, f32) -> f32;
+pub use crate::animations::easing::EasingFn;
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, PartialEq, thiserror::Error)]
 pub enum KeyframeError {
     #[error("Failed to compare keyframe offsets (possible NaN value)")]
     InvalidOffset,
+    #[error("Keyframe animation has no keyframes")]
+    Empty,
+    #[error("Keyframe offset {0} is outside 0.0..=1.0")]
+    OffsetOutOfRange(f32),
+    #[error("Keyframe offsets must not decrease, found {found} after {previous}")]
+    UnorderedOffsets { previous: f32, found: f32 },
+    #[error("More than one keyframe at offset {0}")]
+    DuplicateOffset(f32),
+    #[error("Keyframe animation has no keyframe at offset 0.0")]
+    MissingStart,
+    #[error("Keyframe animation has no keyframe at offset 1.0")]
+    MissingEnd,
 }
 
 #[derive(Clone)]
 pub struct Keyframe<T: Animatable> {
     pub value: T,
     pub offset: f32,
-    pub easing: Option<EasingFn>,
+    pub easing: Option<Easing>,
+}
+
+/// Direction in which a keyframe animation plays its keyframes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyframeDirection {
+    /// From the first keyframe to the last
+    #[default]
+    Normal,
+    /// From the last keyframe to the first
+    Reverse,
+}
+
+/// How a keyframe animation moves between its keyframes
+///
+/// The spline modes pass through every keyframe with a continuous velocity. They use the
+/// type's arithmetic operators, so types whose operators saturate, like colors, are best
+/// animated with [`KeyframeInterpolation::Linear`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyframeInterpolation {
+    /// Straight lines between keyframes, using [`Animatable::interpolate`]
+    #[default]
+    Linear,
+    /// Holds each keyframe's value until the next keyframe is reached
+    Hold,
+    /// Catmull-Rom spline, smooth but may overshoot between keyframes
+    CatmullRom,
+    /// Monotone cubic spline that never overshoots the keyframes around it
+    MonotoneCubic,
 }
 
 #[derive(Clone)]
 pub struct KeyframeAnimation<T: Animatable> {
     pub keyframes: Vec<Keyframe<T>>,
     pub duration: Duration,
+    /// How the animation should loop
+    /// Alternating loops flip the direction after every iteration
+    pub loop_mode: Option<LoopMode>,
+    /// Delay before the animation starts
+    pub delay: Duration,
+    /// Direction of the first iteration
+    pub direction: KeyframeDirection,
+    /// Callback when the animation completes
+    pub on_complete: Option<OnComplete>,
+    /// How values are interpolated between keyframes
+    pub interpolation: KeyframeInterpolation,
 }
 
 impl<T: Animatable> KeyframeAnimation<T> {
@@ -28,14 +81,215 @@ impl<T: Animatable> KeyframeAnimation<T> {
         Self {
             keyframes: Vec::new(),
             duration,
+            loop_mode: None,
+            delay: Duration::default(),
+            direction: KeyframeDirection::default(),
+            on_complete: None,
+            interpolation: KeyframeInterpolation::default(),
         }
     }
 
+    /// Sets how values are interpolated between keyframes
+    pub fn with_interpolation(mut self, interpolation: KeyframeInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Sets the loop mode for the animation
+    pub fn with_loop(mut self, loop_mode: LoopMode) -> Self {
+        self.loop_mode = Some(loop_mode);
+        self
+    }
+
+    /// Sets a delay before the animation starts
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the direction of the first iteration
+    pub fn with_direction(mut self, direction: KeyframeDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets a callback to be called when the animation completes
+    pub fn with_on_complete<F>(mut self, f: F) -> Self
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.on_complete = Some(Arc::new(Mutex::new(f)));
+        self
+    }
+
+    /// Gets the animation config carrying this animation's loop mode, delay and callback
+    pub fn config(&self) -> AnimationConfig {
+        playback_config(self.loop_mode, self.delay, self.on_complete.clone())
+    }
+
     pub fn add_keyframe(
-        mut self,
+        self,
         value: T,
         offset: f32,
         easing: Option<EasingFn>,
+    ) -> Result<Self, KeyframeError> {
+        self.push_keyframe(value, offset, easing.map(Easing::Function))
+    }
+
+    /// Adds a keyframe whose segment from the previous keyframe uses `easing`
+    ///
+    /// Accepts any [`Easing`], such as a
+    /// [`CubicBezier`](crate::animations::easing::CubicBezier) from a design token.
+    pub fn add_keyframe_with_curve(
+        self,
+        value: T,
+        offset: f32,
+        easing: impl Into<Easing>,
+    ) -> Result<Self, KeyframeError> {
+        self.push_keyframe(value, offset, Some(easing.into()))
+    }
+
+    /// Starts building an animation of `duration` whose keyframe offsets may be omitted
+    ///
+    /// See [`KeyframeBuilder`].
+    pub fn builder(duration: Duration) -> KeyframeBuilder<T> {
+        KeyframeBuilder {
+            duration: Some(duration),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Starts building an animation from keyframes at absolute times
+    ///
+    /// The duration is the time of the latest keyframe. See [`KeyframeBuilder`].
+    pub fn timed() -> KeyframeBuilder<T> {
+        KeyframeBuilder {
+            duration: None,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Checks that the animation has keyframes at 0.0 and 1.0 and no two at the same offset
+    pub fn validate(&self) -> Result<(), KeyframeError> {
+        let (Some(first), Some(last)) = (self.keyframes.first(), self.keyframes.last()) else {
+            return Err(KeyframeError::Empty);
+        };
+        for pair in self.keyframes.windows(2) {
+            let (previous, found) = (pair[0].offset, pair[1].offset);
+            if previous.is_nan() || found.is_nan() {
+                return Err(KeyframeError::InvalidOffset);
+            }
+            if found == previous {
+                return Err(KeyframeError::DuplicateOffset(found));
+            }
+            if found < previous {
+                return Err(KeyframeError::UnorderedOffsets { previous, found });
+            }
+        }
+        if first.offset != 0.0 {
+            return Err(KeyframeError::MissingStart);
+        }
+        if last.offset != 1.0 {
+            return Err(KeyframeError::MissingEnd);
+        }
+        Ok(())
+    }
+
+    /// Creates an empty-length animation that starts at `value`
+    ///
+    /// Append segments with [`KeyframeAnimation::then`]; the duration grows with each one.
+    pub fn starting_at(value: T) -> Self {
+        Self {
+            keyframes: vec![Keyframe {
+                value,
+                offset: 0.0,
+                easing: None,
+            }],
+            ..Self::new(Duration::ZERO)
+        }
+    }
+
+    /// Appends a segment from the last keyframe to `value`, animated with `mode`
+    ///
+    /// Tweens last their own duration with their easing. Springs last until they settle
+    /// over the segment's distance and keep their overshoot. Decays glide into `value`
+    /// as if flung just hard enough to come to rest on it. The animation's duration grows
+    /// by the segment's length and earlier offsets are rescaled to keep their timing.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    /// use dioxus_motion::keyframes::KeyframeAnimation;
+    ///
+    /// // Move with a spring, then fade out with a 200ms ease-out tween
+    /// let track = KeyframeAnimation::starting_at(0.0f32)
+    ///     .then(100.0, AnimationMode::Spring(Spring::default()))
+    ///     .then(
+    ///         0.0,
+    ///         AnimationMode::Tween(
+    ///             Tween::new(Duration::from_millis(200)).with_curve(CubicBezier::EASE_OUT),
+    ///         ),
+    ///     );
+    /// assert_eq!(track.keyframes.len(), 3);
+    /// assert!(track.duration > Duration::from_millis(200));
+    /// ```
+    pub fn then(mut self, value: T, mode: AnimationMode) -> Self {
+        let from = match self.keyframes.last() {
+            Some(last) => last.value,
+            None => {
+                self.keyframes.push(Keyframe {
+                    value,
+                    offset: 0.0,
+                    easing: None,
+                });
+                value
+            }
+        };
+        let distance = (value - from).magnitude();
+
+        let (length, easing) = match mode {
+            AnimationMode::Tween(tween) => (tween.duration, tween.easing),
+            AnimationMode::Spring(spring) => {
+                let easing = SpringEasing::for_distance(spring, distance, T::epsilon());
+                (easing.duration(), Easing::Spring(easing))
+            }
+            AnimationMode::Decay(decay) => {
+                let lambda = decay.decay_constant();
+                let length = decay.settling_time(distance * lambda, T::epsilon());
+                let secs = length.as_secs_f32();
+                let end = 1.0 - (-lambda * secs).exp();
+                let easing = Easing::custom(move |t| {
+                    if t >= 1.0 || end <= 0.0 {
+                        1.0
+                    } else {
+                        (1.0 - (-lambda * secs * t.max(0.0)).exp()) / end
+                    }
+                });
+                (length, easing)
+            }
+        };
+
+        let total = self.duration + length;
+        if !total.is_zero() {
+            let scale = self.duration.as_secs_f32() / total.as_secs_f32();
+            for keyframe in &mut self.keyframes {
+                keyframe.offset *= scale;
+            }
+        }
+        self.duration = total;
+        self.keyframes.push(Keyframe {
+            value,
+            offset: 1.0,
+            easing: Some(easing),
+        });
+        self
+    }
+
+    fn push_keyframe(
+        mut self,
+        value: T,
+        offset: f32,
+        easing: Option<Easing>,
     ) -> Result<Self, KeyframeError> {
         self.keyframes.push(Keyframe {
             value,
@@ -60,4 +314,783 @@ impl<T: Animatable> KeyframeAnimation<T> {
         }
         Ok(self)
     }
+
+    /// Samples the animation at `progress` (0.0 to 1.0 of its duration)
+    ///
+    /// Returns `None` if the animation has no keyframes. Only the segment around
+    /// `progress` is evaluated; use [`KeyframeAnimation::compile`] to sample the same
+    /// animation repeatedly without recomputing its spans and tangents.
+    pub fn sample(&self, progress: f32) -> Option<T> {
+        let keyframes = &self.keyframes;
+        let (first, last) = (keyframes.first()?, keyframes.last()?);
+        let progress = progress.clamp(0.0, 1.0);
+        if keyframes.len() == 1 || progress < first.offset {
+            return Some(first.value);
+        }
+        if progress > last.offset {
+            return Some(last.value);
+        }
+
+        let segment = keyframes
+            .partition_point(|keyframe| keyframe.offset < progress)
+            .saturating_sub(1)
+            .min(keyframes.len() - 2);
+        let (start, end) = (&keyframes[segment], &keyframes[segment + 1]);
+        let span = end.offset - start.offset;
+        let local_progress = if span > 0.0 {
+            (progress - start.offset) / span
+        } else {
+            1.0
+        };
+
+        Some(interpolate_segment(
+            self.interpolation,
+            start.value,
+            end.value,
+            end.easing.as_ref(),
+            local_progress,
+            span > 0.0,
+            || {
+                (
+                    self.tangent(segment) * span,
+                    self.tangent(segment + 1) * span,
+                )
+            },
+        ))
+    }
+
+    /// Checks the animation and compiles it into an immutable [`KeyframeTrack`]
+    pub fn compile(&self) -> Result<KeyframeTrack<T>, KeyframeError> {
+        self.validate()?;
+        Ok(self.track())
+    }
+
+    /// Compiles the animation without validating it, for tracks built with
+    /// [`KeyframeAnimation::add_keyframe`] that may lack endpoints
+    pub(crate) fn track(&self) -> KeyframeTrack<T> {
+        let segments = self.keyframes.len().saturating_sub(1);
+        let mut inverse_spans = Vec::with_capacity(segments);
+        let mut easings = Vec::with_capacity(segments);
+        let mut tangents = Vec::new();
+        let spline = matches!(
+            self.interpolation,
+            KeyframeInterpolation::CatmullRom | KeyframeInterpolation::MonotoneCubic
+        );
+
+        for (index, pair) in self.keyframes.windows(2).enumerate() {
+            let span = pair[1].offset - pair[0].offset;
+            inverse_spans.push(if span > 0.0 { 1.0 / span } else { 0.0 });
+            easings.push(pair[1].easing.clone());
+            if spline {
+                tangents.push((self.tangent(index) * span, self.tangent(index + 1) * span));
+            }
+        }
+
+        KeyframeTrack {
+            offsets: self.keyframes.iter().map(|k| k.offset).collect(),
+            values: self.keyframes.iter().map(|k| k.value).collect(),
+            inverse_spans: inverse_spans.into(),
+            easings: easings.into(),
+            tangents: tangents.into(),
+            interpolation: self.interpolation,
+            duration: self.duration,
+            loop_mode: self.loop_mode,
+            delay: self.delay,
+            direction: self.direction,
+            on_complete: self.on_complete.clone(),
+        }
+    }
+
+    /// Gets the slope at keyframe `index` in value per unit of progress
+    fn tangent(&self, index: usize) -> T {
+        let keyframes = &self.keyframes;
+        let secant = |from: &Keyframe<T>, to: &Keyframe<T>| {
+            let span = to.offset - from.offset;
+            (span > 0.0).then(|| (to.value - from.value) * (1.0 / span))
+        };
+        let before = index
+            .checked_sub(1)
+            .and_then(|previous| secant(&keyframes[previous], &keyframes[index]));
+        let after = keyframes
+            .get(index + 1)
+            .and_then(|next| secant(&keyframes[index], next));
+
+        match self.interpolation {
+            KeyframeInterpolation::CatmullRom => {
+                let previous = &keyframes[index.saturating_sub(1)];
+                let next = &keyframes[(index + 1).min(keyframes.len() - 1)];
+                secant(previous, next).unwrap_or_else(T::zero)
+            }
+            _ => match (before, after) {
+                // Fritsch-Carlson: flat at extrema, and no steeper than three times either
+                // secant so the curve cannot overshoot its neighbours
+                (Some(before), Some(after)) if dot(before, after) > 0.0 => {
+                    let tangent = (before + after) * 0.5;
+                    let limit = 3.0 * before.magnitude().min(after.magnitude());
+                    let magnitude = tangent.magnitude();
+                    if magnitude > limit {
+                        tangent * (limit / magnitude)
+                    } else {
+                        tangent
+                    }
+                }
+                (Some(_), Some(_)) | (None, None) => T::zero(),
+                (Some(secant), None) | (None, Some(secant)) => secant,
+            },
+        }
+    }
+}
+
+/// Gets an animation config with the playback settings of a keyframe animation
+fn playback_config(
+    loop_mode: Option<LoopMode>,
+    delay: Duration,
+    on_complete: Option<OnComplete>,
+) -> AnimationConfig {
+    AnimationConfig {
+        loop_mode,
+        delay,
+        on_complete,
+        ..AnimationConfig::default()
+    }
+}
+
+/// Evaluates one segment at `local_progress` (0.0 to 1.0 between its keyframes)
+///
+/// `tangents` gives the span-scaled end tangents and is only called for splines over
+/// segments with a length.
+fn interpolate_segment<T: Animatable>(
+    interpolation: KeyframeInterpolation,
+    start: T,
+    end: T,
+    easing: Option<&Easing>,
+    local_progress: f32,
+    has_length: bool,
+    tangents: impl FnOnce() -> (T, T),
+) -> T {
+    let t = easing.map_or(local_progress, |easing| easing.ease(local_progress));
+    match interpolation {
+        KeyframeInterpolation::Linear => start.interpolate(&end, t),
+        KeyframeInterpolation::Hold if t >= 1.0 => end,
+        KeyframeInterpolation::Hold => start,
+        KeyframeInterpolation::CatmullRom | KeyframeInterpolation::MonotoneCubic if !has_length => {
+            start.interpolate(&end, t)
+        }
+        KeyframeInterpolation::CatmullRom | KeyframeInterpolation::MonotoneCubic => {
+            let (m0, m1) = tangents();
+            hermite(start, end, m0, m1, t)
+        }
+    }
+}
+
+/// Evaluates the cubic Hermite curve from `from` to `to` with end tangents `m0` and `m1`
+fn hermite<T: Animatable>(from: T, to: T, m0: T, m1: T, t: f32) -> T {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    from * (2.0 * t3 - 3.0 * t2 + 1.0)
+        + to * (3.0 * t2 - 2.0 * t3)
+        + m0 * (t3 - 2.0 * t2 + t)
+        + m1 * (t3 - t2)
+}
+
+/// Gets the dot product of two values from their magnitudes (polarization identity)
+fn dot<T: Animatable>(a: T, b: T) -> f32 {
+    let sum = (a + b).magnitude();
+    let difference = (a - b).magnitude();
+    (sum * sum - difference * difference) / 4.0
+}
+
+impl<T: Animatable + Send + 'static> KeyframeAnimation<T> {
+    /// Runs the animation offline and returns every sampled value
+    ///
+    /// See [`Motion::simulate`](crate::motion::Motion::simulate) for sampling details.
+    pub fn simulate(&self, dt: f32) -> crate::motion::Trajectory<T> {
+        crate::motion::Motion::simulate_keyframes(self.clone(), dt)
+    }
+}
+
+/// A compiled, immutable keyframe track
+///
+/// Segment boundaries, inverse spans and spline tangents are computed once, and sampling
+/// finds the segment by binary search, so long tracks cost `O(log n)` per frame instead of
+/// a scan over every keyframe. Built by [`KeyframeAnimation::compile`], the track keeps the
+/// animation's loop mode, delay, direction and callback, and one track can be shared by many
+/// motions with [`Motion::animate_track`](crate::motion::Motion::animate_track).
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+/// use dioxus_motion::keyframes::KeyframeAnimation;
+///
+/// let track = KeyframeAnimation::builder(Duration::from_secs(1))
+///     .keyframe(0.0f32)
+///     .keyframe(50.0)
+///     .keyframe(100.0)
+///     .build()
+///     .unwrap()
+///     .compile()
+///     .unwrap();
+/// assert_eq!(track.sample(0.25), Some(25.0));
+/// ```
+#[derive(Clone)]
+pub struct KeyframeTrack<T: Animatable> {
+    offsets: Box<[f32]>,
+    values: Box<[T]>,
+    /// `1 / span` of each segment, 0.0 for segments without length
+    inverse_spans: Box<[f32]>,
+    /// Easing of each segment
+    easings: Box<[Option<Easing>]>,
+    /// Start and end tangents of each segment scaled by its span, only for splines
+    tangents: Box<[(T, T)]>,
+    interpolation: KeyframeInterpolation,
+    duration: Duration,
+    loop_mode: Option<LoopMode>,
+    delay: Duration,
+    direction: KeyframeDirection,
+    on_complete: Option<OnComplete>,
+}
+
+impl<T: Animatable> KeyframeTrack<T> {
+    /// Gets the duration of one iteration
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Gets the direction of the first iteration
+    pub fn direction(&self) -> KeyframeDirection {
+        self.direction
+    }
+
+    /// Gets the animation config carrying the track's loop mode, delay and callback
+    pub fn config(&self) -> AnimationConfig {
+        playback_config(self.loop_mode, self.delay, self.on_complete.clone())
+    }
+
+    /// Gets the number of keyframes
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Checks whether the track has no keyframes
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Samples the track `progress` (0.0 to 1.0) through an iteration played `reversed`
+    pub(crate) fn sample_directed(&self, progress: f32, reversed: bool) -> Option<T> {
+        self.sample(if reversed { 1.0 - progress } else { progress })
+    }
+
+    /// Samples the track at `progress` (0.0 to 1.0 of its duration)
+    ///
+    /// Returns `None` if the track has no keyframes.
+    pub fn sample(&self, progress: f32) -> Option<T> {
+        let (&first, &last) = (self.values.first()?, self.values.last()?);
+        let progress = progress.clamp(0.0, 1.0);
+        if self.values.len() == 1 || progress < self.offsets[0] {
+            return Some(first);
+        }
+        if progress > self.offsets[self.offsets.len() - 1] {
+            return Some(last);
+        }
+
+        // The segment ending at the first offset not before `progress`
+        let segment = self
+            .offsets
+            .partition_point(|&offset| offset < progress)
+            .saturating_sub(1)
+            .min(self.values.len() - 2);
+        let (start, end) = (self.values[segment], self.values[segment + 1]);
+        let inverse_span = self.inverse_spans[segment];
+
+        let local_progress = if inverse_span == 0.0 {
+            1.0
+        } else {
+            (progress - self.offsets[segment]) * inverse_span
+        };
+
+        Some(interpolate_segment(
+            self.interpolation,
+            start,
+            end,
+            self.easings[segment].as_ref(),
+            local_progress,
+            inverse_span != 0.0,
+            || self.tangents[segment],
+        ))
+    }
+}
+
+/// Where a keyframe added to a [`KeyframeBuilder`] sits on the timeline
+#[derive(Debug, Clone, Copy)]
+enum Position {
+    Auto,
+    Offset(f32),
+    Time(Duration),
+}
+
+/// Builds a [`KeyframeAnimation`] from keyframes with optional offsets or absolute times
+///
+/// Like CSS and the Web Animations API, keyframes without an offset are spaced evenly
+/// between their neighbours, and the first and last default to 0.0 and 1.0. Keyframes at
+/// absolute times are placed relative to the duration, which [`KeyframeAnimation::timed`]
+/// infers from the latest time. [`KeyframeBuilder::build`] validates the result.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+/// use dioxus_motion::keyframes::KeyframeAnimation;
+///
+/// let bounce = KeyframeAnimation::builder(Duration::from_secs(1))
+///     .keyframe(0.0f32)
+///     .keyframe(100.0)
+///     .keyframe(80.0)
+///     .keyframe(100.0)
+///     .build()
+///     .unwrap();
+/// assert_eq!(bounce.keyframes[2].offset, 2.0 / 3.0);
+///
+/// let timed = KeyframeAnimation::timed()
+///     .keyframe_at_time(0.0f32, Duration::ZERO)
+///     .keyframe_at_time(100.0, Duration::from_millis(300))
+///     .with_easing(CubicBezier::EASE_OUT)
+///     .keyframe_at_time(0.0, Duration::from_millis(400))
+///     .build()
+///     .unwrap();
+/// assert_eq!(timed.duration, Duration::from_millis(400));
+/// assert_eq!(timed.keyframes[1].offset, 0.75);
+/// ```
+#[derive(Clone)]
+pub struct KeyframeBuilder<T: Animatable> {
+    duration: Option<Duration>,
+    entries: Vec<(T, Position, Option<Easing>)>,
+}
+
+impl<T: Animatable> KeyframeBuilder<T> {
+    /// Adds a keyframe spaced evenly between its neighbours
+    pub fn keyframe(self, value: T) -> Self {
+        self.push(value, Position::Auto)
+    }
+
+    /// Adds a keyframe at `offset` (0.0 to 1.0 of the duration)
+    pub fn keyframe_at(self, value: T, offset: f32) -> Self {
+        self.push(value, Position::Offset(offset))
+    }
+
+    /// Adds a keyframe at `time` since the start of the animation
+    pub fn keyframe_at_time(self, value: T, time: Duration) -> Self {
+        self.push(value, Position::Time(time))
+    }
+
+    /// Sets the easing of the segment leading into the last added keyframe
+    pub fn with_easing(mut self, easing: impl Into<Easing>) -> Self {
+        if let Some(entry) = self.entries.last_mut() {
+            entry.2 = Some(easing.into());
+        }
+        self
+    }
+
+    fn push(mut self, value: T, position: Position) -> Self {
+        self.entries.push((value, position, None));
+        self
+    }
+
+    /// Resolves every offset and builds the validated animation
+    pub fn build(self) -> Result<KeyframeAnimation<T>, KeyframeError> {
+        if self.entries.is_empty() {
+            return Err(KeyframeError::Empty);
+        }
+        let duration = self.duration.unwrap_or_else(|| {
+            self.entries
+                .iter()
+                .filter_map(|(_, position, _)| match position {
+                    Position::Time(time) => Some(*time),
+                    _ => None,
+                })
+                .max()
+                .unwrap_or_default()
+        });
+
+        let mut offsets: Vec<Option<f32>> = self
+            .entries
+            .iter()
+            .map(|(_, position, _)| match *position {
+                Position::Auto => None,
+                Position::Offset(offset) => Some(offset),
+                Position::Time(time) if duration.is_zero() => {
+                    Some(if time.is_zero() { 0.0 } else { f32::INFINITY })
+                }
+                Position::Time(time) => Some(time.as_secs_f32() / duration.as_secs_f32()),
+            })
+            .collect();
+        for offset in offsets.iter().flatten() {
+            if offset.is_nan() {
+                return Err(KeyframeError::InvalidOffset);
+            }
+            if !(0.0..=1.0).contains(offset) {
+                return Err(KeyframeError::OffsetOutOfRange(*offset));
+            }
+        }
+        distribute_offsets(&mut offsets);
+
+        let animation = KeyframeAnimation {
+            keyframes: self
+                .entries
+                .into_iter()
+                .zip(offsets)
+                .map(|((value, _, easing), offset)| Keyframe {
+                    value,
+                    offset: offset.unwrap_or_default(),
+                    easing,
+                })
+                .collect(),
+            ..KeyframeAnimation::new(duration)
+        };
+        animation.validate()?;
+        Ok(animation)
+    }
+}
+
+/// Fills missing offsets the way the Web Animations API does
+///
+/// The first and last keyframes default to 0.0 and 1.0, and every run of missing offsets
+/// is spaced evenly between the offsets around it.
+fn distribute_offsets(offsets: &mut [Option<f32>]) {
+    let len = offsets.len();
+    if let Some(last) = offsets.last_mut() {
+        last.get_or_insert(1.0);
+    }
+    if len > 1 {
+        offsets[0].get_or_insert(0.0);
+    }
+
+    let mut start = 0;
+    for end in 1..len {
+        let Some(to) = offsets[end] else {
+            continue;
+        };
+        let from = offsets[start].unwrap_or_default();
+        let gaps = (end - start) as f32;
+        for (step, offset) in offsets[start + 1..end].iter_mut().enumerate() {
+            *offset = Some(from + (to - from) * (step + 1) as f32 / gaps);
+        }
+        start = end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn offsets(animation: &KeyframeAnimation<f32>) -> Vec<f32> {
+        animation.keyframes.iter().map(|k| k.offset).collect()
+    }
+
+    #[test]
+    fn test_builder_spaces_missing_offsets_evenly() {
+        let animation = KeyframeAnimation::builder(Duration::from_secs(1))
+            .keyframe(0.0f32)
+            .keyframe(1.0)
+            .keyframe_at(2.0, 0.8)
+            .keyframe(3.0)
+            .keyframe(4.0)
+            .keyframe(5.0)
+            .build()
+            .unwrap();
+        let expected = [0.0, 0.4, 0.8, 0.8 + 0.2 / 3.0, 0.8 + 0.4 / 3.0, 1.0];
+        for (offset, expected) in offsets(&animation).into_iter().zip(expected) {
+            assert!((offset - expected).abs() < 1e-6);
+        }
+        assert_eq!(animation.duration, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_timed_builder_infers_duration() {
+        let animation = KeyframeAnimation::timed()
+            .keyframe_at_time(0.0f32, Duration::ZERO)
+            .keyframe(5.0)
+            .keyframe_at_time(10.0, Duration::from_millis(200))
+            .keyframe_at_time(20.0, Duration::from_millis(800))
+            .build()
+            .unwrap();
+        assert_eq!(animation.duration, Duration::from_millis(800));
+        assert_eq!(offsets(&animation), [0.0, 0.125, 0.25, 1.0]);
+
+        // With an explicit duration, times must still cover 0% to 100%
+        let result = KeyframeAnimation::builder(Duration::from_secs(1))
+            .keyframe_at_time(0.0f32, Duration::ZERO)
+            .keyframe_at_time(1.0, Duration::from_millis(500))
+            .build();
+        assert_eq!(result.err(), Some(KeyframeError::MissingEnd));
+        let result = KeyframeAnimation::builder(Duration::from_secs(1))
+            .keyframe(0.0f32)
+            .keyframe_at_time(1.0, Duration::from_secs(2))
+            .build();
+        assert_eq!(result.err(), Some(KeyframeError::OffsetOutOfRange(2.0)));
+    }
+
+    #[test]
+    fn test_builder_keeps_easing_per_segment() {
+        let animation = KeyframeAnimation::builder(Duration::from_secs(1))
+            .keyframe(0.0f32)
+            .keyframe(10.0)
+            .with_easing(crate::animations::easing::CubicBezier::EASE_IN)
+            .keyframe(20.0)
+            .build()
+            .unwrap();
+        assert!(animation.keyframes[0].easing.is_none());
+        assert!(animation.keyframes[1].easing.is_some());
+        assert!(animation.keyframes[2].easing.is_none());
+    }
+
+    fn spline(interpolation: KeyframeInterpolation) -> KeyframeAnimation<f32> {
+        KeyframeAnimation::builder(Duration::from_secs(1))
+            .keyframe(0.0f32)
+            .keyframe(10.0)
+            .keyframe(10.0)
+            .keyframe(30.0)
+            .build()
+            .unwrap()
+            .with_interpolation(interpolation)
+    }
+
+    fn slope(animation: &KeyframeAnimation<f32>, progress: f32) -> f32 {
+        let h = 1e-3;
+        (animation.sample(progress + h).unwrap() - animation.sample(progress - h).unwrap())
+            / (2.0 * h)
+    }
+
+    #[test]
+    fn test_hold_interpolation_steps_at_keyframes() {
+        let hold = spline(KeyframeInterpolation::Hold);
+        assert_eq!(hold.sample(0.0), Some(0.0));
+        assert_eq!(hold.sample(0.3), Some(0.0));
+        assert_eq!(hold.sample(1.0 / 3.0 + 0.01), Some(10.0));
+        assert_eq!(hold.sample(0.99), Some(10.0));
+        assert_eq!(hold.sample(1.0), Some(30.0));
+    }
+
+    #[test]
+    fn test_splines_pass_through_keyframes_smoothly() {
+        for interpolation in [
+            KeyframeInterpolation::CatmullRom,
+            KeyframeInterpolation::MonotoneCubic,
+        ] {
+            let animation = KeyframeAnimation::builder(Duration::from_secs(1))
+                .keyframe(0.0f32)
+                .keyframe(10.0)
+                .keyframe(40.0)
+                .keyframe(50.0)
+                .build()
+                .unwrap()
+                .with_interpolation(interpolation);
+            for keyframe in &animation.keyframes {
+                let value = animation.sample(keyframe.offset).unwrap();
+                assert!((value - keyframe.value).abs() < 1e-4);
+            }
+            // No kink at the interior keyframes
+            for offset in [1.0 / 3.0, 2.0 / 3.0] {
+                let (left, right) = (
+                    slope(&animation, offset - 0.002),
+                    slope(&animation, offset + 0.002),
+                );
+                assert!(
+                    (left - right).abs() < 5.0,
+                    "{interpolation:?} {left} {right}"
+                );
+            }
+        }
+
+        let linear = KeyframeAnimation::builder(Duration::from_secs(1))
+            .keyframe(0.0f32)
+            .keyframe(10.0)
+            .keyframe(40.0)
+            .build()
+            .unwrap();
+        assert!((slope(&linear, 0.498) - slope(&linear, 0.502)).abs() > 30.0);
+    }
+
+    #[test]
+    fn test_monotone_cubic_does_not_overshoot() {
+        let catmull_rom = spline(KeyframeInterpolation::CatmullRom);
+        let monotone = spline(KeyframeInterpolation::MonotoneCubic);
+
+        let flat = |animation: &KeyframeAnimation<f32>| {
+            (1..100)
+                .map(|i| animation.sample(1.0 / 3.0 + i as f32 / 300.0).unwrap())
+                .fold(0.0f32, |max, value| max.max((value - 10.0).abs()))
+        };
+        assert!(flat(&catmull_rom) > 0.5);
+        assert!(flat(&monotone) < 1e-4);
+
+        let mut previous = 0.0;
+        for i in 0..=300 {
+            let value = monotone.sample(i as f32 / 300.0).unwrap();
+            assert!(value >= previous - 1e-4);
+            previous = value;
+        }
+    }
+
+    #[test]
+    fn test_monotone_cubic_with_transforms() {
+        use crate::animations::transform::Transform;
+
+        let animation = KeyframeAnimation::builder(Duration::from_secs(1))
+            .keyframe(Transform::new(0.0, 0.0, 1.0, 0.0))
+            .keyframe(Transform::new(10.0, 20.0, 1.0, 0.0))
+            .keyframe(Transform::new(20.0, 20.0, 1.0, 0.0))
+            .build()
+            .unwrap()
+            .with_interpolation(KeyframeInterpolation::MonotoneCubic);
+        let middle = animation.sample(0.5).unwrap();
+        assert!((middle.x - 10.0).abs() < 1e-4 && (middle.y - 20.0).abs() < 1e-4);
+        let quarter = animation.sample(0.25).unwrap();
+        assert!(quarter.x > 0.0 && quarter.x < 10.0 && quarter.y < 20.0);
+    }
+
+    #[test]
+    fn test_direct_sampling_matches_compiled_splines() {
+        for interpolation in [
+            KeyframeInterpolation::CatmullRom,
+            KeyframeInterpolation::MonotoneCubic,
+            KeyframeInterpolation::Hold,
+        ] {
+            let animation = KeyframeAnimation::builder(Duration::from_secs(1))
+                .keyframe(0.0f32)
+                .keyframe(80.0)
+                .with_easing(crate::animations::easing::CubicBezier::EASE_IN_OUT)
+                .keyframe(20.0)
+                .keyframe(100.0)
+                .build()
+                .unwrap()
+                .with_interpolation(interpolation);
+            let track = animation.compile().unwrap();
+            for i in 0..=100 {
+                let progress = i as f32 / 100.0;
+                let (direct, compiled) = (animation.sample(progress), track.sample(progress));
+                assert!(
+                    (direct.unwrap() - compiled.unwrap()).abs() < 1e-3,
+                    "{progress}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_compiled_track_matches_linear_scan() {
+        let animation = KeyframeAnimation::new(Duration::from_secs(1))
+            .add_keyframe(0.0f32, 0.0, None)
+            .unwrap()
+            .add_keyframe(10.0, 0.25, None)
+            .unwrap()
+            .add_keyframe(20.0, 0.5, None)
+            .unwrap()
+            .add_keyframe(-5.0, 0.5, None)
+            .unwrap()
+            .add_keyframe(40.0, 0.9, None)
+            .unwrap();
+        let track = animation.track();
+        assert_eq!(track.len(), 5);
+
+        let reference = |progress: f32| {
+            let (start, end) = animation
+                .keyframes
+                .windows(2)
+                .find(|w| progress >= w[0].offset && progress <= w[1].offset)
+                .map_or_else(
+                    || {
+                        let last = animation.keyframes.last().unwrap();
+                        (last, last)
+                    },
+                    |w| (&w[0], &w[1]),
+                );
+            let t = if start.offset == end.offset {
+                1.0
+            } else {
+                (progress - start.offset) / (end.offset - start.offset)
+            };
+            start.value.interpolate(&end.value, t)
+        };
+        for i in 0..=200 {
+            let progress = i as f32 / 200.0;
+            let sampled = track.sample(progress).unwrap();
+            assert!((sampled - reference(progress)).abs() < 1e-4, "{progress}");
+            assert!((animation.sample(progress).unwrap() - sampled).abs() < 1e-4);
+        }
+
+        // Jumps take the later keyframe right after the shared offset
+        assert_eq!(track.sample(0.5), Some(20.0));
+        assert!((track.sample(0.5001).unwrap() + 5.0).abs() < 0.1);
+        assert_eq!(
+            animation.compile().err(),
+            Some(KeyframeError::DuplicateOffset(0.5))
+        );
+        assert!(
+            KeyframeAnimation::<f32>::new(Duration::ZERO)
+                .track()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let empty = KeyframeAnimation::<f32>::builder(Duration::from_secs(1)).build();
+        assert_eq!(empty.err(), Some(KeyframeError::Empty));
+        assert_eq!(
+            KeyframeAnimation::<f32>::new(Duration::from_secs(1)).validate(),
+            Err(KeyframeError::Empty)
+        );
+
+        let duplicate = KeyframeAnimation::builder(Duration::from_secs(1))
+            .keyframe(0.0f32)
+            .keyframe_at(1.0, 0.5)
+            .keyframe_at(2.0, 0.5)
+            .keyframe(3.0)
+            .build();
+        assert_eq!(duplicate.err(), Some(KeyframeError::DuplicateOffset(0.5)));
+
+        let unordered = KeyframeAnimation::builder(Duration::from_secs(1))
+            .keyframe(0.0f32)
+            .keyframe_at(1.0, 0.6)
+            .keyframe_at(2.0, 0.3)
+            .keyframe(3.0)
+            .build();
+        assert_eq!(
+            unordered.err(),
+            Some(KeyframeError::UnorderedOffsets {
+                previous: 0.6,
+                found: 0.3
+            })
+        );
+
+        let no_start = KeyframeAnimation::builder(Duration::from_secs(1))
+            .keyframe_at(0.0f32, 0.2)
+            .keyframe(1.0)
+            .build();
+        assert_eq!(no_start.err(), Some(KeyframeError::MissingStart));
+        let single = KeyframeAnimation::builder(Duration::from_secs(1))
+            .keyframe(1.0f32)
+            .build();
+        assert_eq!(single.err(), Some(KeyframeError::MissingStart));
+
+        let nan = KeyframeAnimation::builder(Duration::from_secs(1))
+            .keyframe(0.0f32)
+            .keyframe_at(1.0, f32::NAN)
+            .keyframe(2.0)
+            .build();
+        assert_eq!(nan.err(), Some(KeyframeError::InvalidOffset));
+
+        // Tracks from add_keyframe can be checked too
+        let track = KeyframeAnimation::new(Duration::from_secs(1))
+            .add_keyframe(0.0f32, 0.0, None)
+            .unwrap()
+            .add_keyframe(1.0, 0.9, None)
+            .unwrap();
+        assert_eq!(track.validate(), Err(KeyframeError::MissingEnd));
+        assert_eq!(
+            track.add_keyframe(2.0, 1.0, None).unwrap().validate(),
+            Ok(())
+        );
+    }
 }
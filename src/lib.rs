@@ -12,6 +12,7 @@
 //! - Transform animations
 //! - Configurable animation loops
 //! - Animation sequences with atomic step management
+//! - Timelines that orchestrate many motions with relative positions and labels
 //! - Single default epsilon (0.01) for consistent animation completion
 //! - Automatic resource pool management for maximum performance
 //!
@@ -98,6 +99,7 @@
 #![deny(clippy::option_if_let_else)] // Prefer map/and_then
 
 use animations::core::Animatable;
+use dioxus::dioxus_core::use_drop;
 use dioxus::prelude::*;
 pub use instant::Duration;
 
@@ -106,17 +108,26 @@ pub mod keyframes;
 pub mod manager;
 pub mod motion;
 pub mod pool;
+pub mod scheduler;
 pub mod sequence;
+pub mod timeline;
 #[cfg(feature = "transitions")]
 pub mod transitions;
 
+#[cfg(test)]
+mod test_utils;
+
 #[cfg(feature = "transitions")]
 pub use dioxus_motion_transitions_macro;
 
-pub use animations::platform::{MotionTime, TimeProvider};
+pub use animations::platform::{ManualClock, MotionClock, MotionTime, TimeProvider};
 
-pub use keyframes::{Keyframe, KeyframeAnimation};
+pub use keyframes::{
+    Keyframe, KeyframeAnimation, KeyframeBuilder, KeyframeDirection, KeyframeInterpolation,
+    KeyframeTrack,
+};
 pub use manager::AnimationManager;
+pub use scheduler::{FrameScheduler, SchedulerLink, use_frame_scheduler};
 
 use motion::Motion;
 
@@ -124,18 +135,27 @@ use motion::Motion;
 pub mod prelude {
     pub use crate::animations::core::{AnimationConfig, AnimationMode, LoopMode};
     pub use crate::animations::{
-        colors::Color, spring::Spring, transform::Transform, tween::Tween,
+        colors::Color,
+        decay::Decay,
+        easing::{CubicBezier, Easing},
+        spring::{Spring, SpringSolver},
+        transform::Transform,
+        tween::Tween,
     };
     #[cfg(feature = "transitions")]
     pub use crate::dioxus_motion_transitions_macro::MotionTransitions;
     pub use crate::sequence::AnimationSequence;
+    pub use crate::timeline::{Position, Timeline, TimelineError, use_timeline};
     #[cfg(feature = "transitions")]
     pub use crate::transitions::config::TransitionVariant;
     #[cfg(feature = "transitions")]
     pub use crate::transitions::page_transitions::TransitionVariantResolver;
     #[cfg(feature = "transitions")]
     pub use crate::transitions::page_transitions::{AnimatableRoute, AnimatedOutlet};
-    pub use crate::{AnimationManager, Duration, Time, TimeProvider, use_motion};
+    pub use crate::{
+        AnimationManager, Duration, FrameScheduler, ManualClock, MotionClock, Time, TimeProvider,
+        use_frame_scheduler, use_motion,
+    };
 
     // Performance optimization exports
     pub use crate::motion::MotionOptimizationStats;
@@ -145,38 +165,15 @@ pub mod prelude {
 
 pub type Time = MotionTime;
 
-/// Helper function to calculate the appropriate delay for the animation loop
-fn calculate_delay(dt: f32, running_frames: u32) -> Duration {
-    #[cfg(feature = "web")]
-    {
-        // running_frames is not used in web builds but kept for API consistency
-        let _ = running_frames;
-        match dt {
-            x if x < 0.008 => Duration::from_millis(8),  // ~120fps
-            x if x < 0.016 => Duration::from_millis(16), // ~60fps
-            _ => Duration::from_millis(32),              // ~30fps
-        }
-    }
-    #[cfg(not(feature = "web"))]
-    {
-        if running_frames <= 200 {
-            Duration::from_micros(8333) // ~120fps
-        } else {
-            match dt {
-                x if x < 0.005 => Duration::from_millis(8),  // ~120fps
-                x if x < 0.011 => Duration::from_millis(16), // ~60fps
-                _ => Duration::from_millis(33),              // ~30fps
-            }
-        }
-    }
-}
-
-/// Creates an animation manager that continuously updates a motion state.
+/// Creates an animation manager driven by the shared frame scheduler.
+///
+/// This function initializes a motion state with the provided initial value and registers it with the
+/// app-wide [`FrameScheduler`]. While the animation is running, the scheduler updates it together with
+/// every other active motion in a single batched frame; once it settles, the motion is deregistered
+/// and costs nothing until the next `animate_*` call wakes it again.
 ///
-/// This function initializes a motion state with the provided initial value and spawns an asynchronous loop
-/// that updates the animation state based on the elapsed time between frames. When the animation is running,
-/// it updates the state using the calculated time delta and dynamically adjusts the update interval to optimize CPU usage;
-/// when the animation is inactive, it waits longer before polling again.
+/// The scheduler reads time from the [`MotionClock`] found in Dioxus context, falling back to
+/// [`MotionTime`]. Provide `MotionClock::of::<ManualClock>()` to step animations deterministically.
 ///
 /// # Example
 ///
@@ -202,51 +199,168 @@ fn calculate_delay(dt: f32, running_frames: u32) -> Duration {
 /// }
 /// ```
 pub fn use_motion<T: Animatable + Send + 'static>(initial: T) -> impl AnimationManager<T> {
-    let mut state = use_signal(|| Motion::new(initial));
-
-    #[cfg(feature = "web")]
-    let idle_poll_rate = Duration::from_millis(100);
-
-    #[cfg(not(feature = "web"))]
-    let idle_poll_rate = Duration::from_millis(33);
-
-    use_effect(move || {
-        // This executes after rendering is complete
-        spawn(async move {
-            let mut last_frame = Time::now();
-            let mut running_frames = 0u32;
-
-            loop {
-                let now = Time::now();
-                let dt = (now.duration_since(last_frame).as_secs_f32()).min(0.1);
-                last_frame = now;
-
-                // Only check if running first, then write to the signal
-                if (*state.peek()).is_running() {
-                    running_frames += 1;
-                    let prev_value = (*state.peek()).get_value();
-                    let updated = (*state.write()).update(dt);
-                    let new_value = (*state.peek()).get_value();
-                    let epsilon = (*state.peek()).get_epsilon();
-                    // Only trigger a re-render if the value changed significantly
-                    if (new_value - prev_value).magnitude() > epsilon || updated {
-                        // State has changed enough, continue
-                    } else {
-                        // Skip this frame's update to avoid unnecessary re-render
-                        let delay = calculate_delay(dt, running_frames);
-                        Time::delay(delay).await;
-                        continue;
-                    }
-
-                    let delay = calculate_delay(dt, running_frames);
-                    Time::delay(delay).await;
-                } else {
-                    running_frames = 0;
-                    Time::delay(idle_poll_rate).await;
+    let scheduler = use_frame_scheduler();
+    let link = use_hook(|| scheduler.reserve());
+
+    let state = use_signal({
+        let link = link.clone();
+        move || Motion::new(initial).with_scheduler(link)
+    });
+
+    use_hook({
+        let scheduler = scheduler.clone();
+        let link = link.clone();
+        move || {
+            let mut state = state;
+            scheduler.attach(&link, move |dt| {
+                if !(*state.peek()).is_running() {
+                    return false;
                 }
-            }
-        });
+                (*state.write()).update(dt);
+                (*state.peek()).is_running()
+            });
+        }
     });
 
+    use_drop(move || scheduler.unregister(&link));
+
     state
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::prelude::{AnimationConfig, AnimationMode, Position, Tween, use_timeline};
+    use std::cell::RefCell;
+
+    thread_local! {
+        static SAMPLES: RefCell<Vec<f32>> = const { RefCell::new(Vec::new()) };
+        static ANIMATE_ROWS: RefCell<bool> = const { RefCell::new(false) };
+        static TIMELINE_SAMPLES: RefCell<Vec<(f32, f32)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn tween_app() -> Element {
+        let mut value = use_motion(0.0f32);
+        use_hook(move || {
+            value.animate_to(
+                100.0,
+                AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(100)))),
+            );
+        });
+        SAMPLES.with(|samples| samples.borrow_mut().push(value.get_value()));
+        rsx! { "{value.get_value()}" }
+    }
+
+    fn many_motions_app() -> Element {
+        let animate = use_hook(|| ANIMATE_ROWS.with(|animate| *animate.borrow()));
+        for _ in 0..50 {
+            let mut value = use_motion(0.0f32);
+            use_hook(move || {
+                if animate {
+                    value.animate_to(
+                        1.0,
+                        AnimationConfig::new(AnimationMode::Tween(Tween::new(
+                            Duration::from_millis(50),
+                        ))),
+                    );
+                }
+            });
+        }
+        rsx! {}
+    }
+
+    fn timeline_app() -> Element {
+        let x = use_motion(0.0f32);
+        let y = use_motion(0.0f32);
+        let mut timeline = use_timeline(move |timeline| {
+            let tween =
+                AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(100))));
+            let x = timeline.track(x);
+            let y = timeline.track(y);
+            timeline
+                .to(x, 100.0, tween.clone(), Position::end())
+                .and_then(|timeline| timeline.to(y, 50.0, tween, "-=50ms"))
+                .unwrap();
+        });
+        use_hook(move || timeline.write().play());
+        TIMELINE_SAMPLES.with(|samples| samples.borrow_mut().push((x.get_value(), y.get_value())));
+        rsx! { "{x.get_value()} {y.get_value()}" }
+    }
+
+    fn step(dom: &mut VirtualDom, frame: Duration) {
+        ManualClock::advance(frame);
+        dom.process_events();
+        dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+    }
+
+    #[test]
+    fn test_use_motion_with_manual_clock_is_deterministic() {
+        let run = || {
+            ManualClock::reset();
+            SAMPLES.with(|samples| samples.borrow_mut().clear());
+
+            let mut dom = VirtualDom::new(tween_app);
+            dom.provide_root_context(MotionClock::of::<ManualClock>());
+            dom.rebuild_in_place();
+
+            for _ in 0..20 {
+                step(&mut dom, Duration::from_millis(16));
+            }
+            SAMPLES.with(|samples| samples.borrow().clone())
+        };
+
+        let first = run();
+        let second = run();
+
+        assert_eq!(first, second);
+        assert!(first.len() > 2, "animation should have re-rendered");
+        assert_eq!(*first.last().unwrap(), 100.0);
+    }
+
+    fn many_motions_dom(animate: bool) -> VirtualDom {
+        ManualClock::reset();
+        ANIMATE_ROWS.with(|rows| *rows.borrow_mut() = animate);
+        let mut dom = VirtualDom::new(many_motions_app);
+        dom.provide_root_context(MotionClock::of::<ManualClock>());
+        dom.rebuild_in_place();
+        dom
+    }
+
+    #[test]
+    fn test_use_timeline_plays_on_the_frame_scheduler() {
+        ManualClock::reset();
+        let mut dom = VirtualDom::new(timeline_app);
+        dom.provide_root_context(MotionClock::of::<ManualClock>());
+        dom.rebuild_in_place();
+
+        for _ in 0..20 {
+            step(&mut dom, Duration::from_millis(16));
+        }
+        let samples = TIMELINE_SAMPLES.with(|samples| samples.borrow().clone());
+        assert!(samples.len() > 2, "timeline should have re-rendered");
+        assert!(samples.iter().any(|&(x, y)| x < 100.0 && y > 0.0));
+        assert_eq!(*samples.last().unwrap(), (100.0, 50.0));
+        assert_eq!(ManualClock::pending_delays(), 0);
+    }
+
+    #[test]
+    fn test_idle_motions_schedule_no_timers() {
+        let mut dom = many_motions_dom(false);
+        step(&mut dom, Duration::from_millis(16));
+        assert_eq!(ManualClock::pending_delays(), 0);
+    }
+
+    #[test]
+    fn test_active_motions_share_one_frame_loop() {
+        let mut dom = many_motions_dom(true);
+        step(&mut dom, Duration::from_millis(16));
+        assert_eq!(ManualClock::pending_delays(), 1);
+
+        // Once every motion settles the scheduler goes back to sleep
+        for _ in 0..10 {
+            step(&mut dom, Duration::from_millis(16));
+        }
+        assert_eq!(ManualClock::pending_delays(), 0);
+    }
+}
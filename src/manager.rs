@@ -1,23 +1,37 @@
 use crate::Duration;
 use crate::animations::core::Animatable;
-use crate::keyframes::KeyframeAnimation;
+use crate::keyframes::{KeyframeAnimation, KeyframeTrack};
 use crate::motion::Motion;
 use crate::prelude::AnimationConfig;
 use crate::sequence::AnimationSequence;
+use std::sync::Arc;
 
 use dioxus::prelude::{ReadableExt, Signal, WritableExt};
 
 pub trait AnimationManager<T: Animatable + Send + 'static>: Clone + Copy {
     fn new(initial: T) -> Self;
     fn animate_to(&mut self, target: T, config: AnimationConfig);
+    fn animate_to_with_velocity(&mut self, target: T, config: AnimationConfig, velocity: T);
+    fn animate_decay(&mut self, velocity: T, config: AnimationConfig);
+    fn animate_decay_within(&mut self, velocity: T, min: T, max: T, config: AnimationConfig);
     fn animate_sequence(&mut self, sequence: AnimationSequence<T>);
     fn animate_keyframes(&mut self, animation: KeyframeAnimation<T>);
+    fn animate_track(&mut self, track: Arc<KeyframeTrack<T>>);
     fn update(&mut self, dt: f32) -> bool;
     fn get_value(&self) -> T;
     fn is_running(&self) -> bool;
     fn reset(&mut self);
     fn stop(&mut self);
+    fn pause(&mut self);
+    fn resume(&mut self);
+    fn is_paused(&self) -> bool;
+    fn reverse_sequence(&mut self) -> bool;
+    fn seek(&mut self, progress: f32) -> bool;
+    fn seek_time(&mut self, time: Duration) -> bool;
+    fn set_scrubbing(&mut self, scrubbing: bool);
+    fn is_scrubbing(&self) -> bool;
     fn delay(&mut self, duration: Duration);
+    fn set_playback_rate(&mut self, rate: f32);
 }
 
 impl<T: Animatable + Send + 'static> AnimationManager<T> for Signal<Motion<T>> {
@@ -29,18 +43,30 @@ impl<T: Animatable + Send + 'static> AnimationManager<T> for Signal<Motion<T>> {
         (*self.write()).animate_to(target, config);
     }
 
+    fn animate_to_with_velocity(&mut self, target: T, config: AnimationConfig, velocity: T) {
+        (*self.write()).animate_to_with_velocity(target, config, velocity);
+    }
+
+    fn animate_decay(&mut self, velocity: T, config: AnimationConfig) {
+        (*self.write()).animate_decay(velocity, config);
+    }
+
+    fn animate_decay_within(&mut self, velocity: T, min: T, max: T, config: AnimationConfig) {
+        (*self.write()).animate_decay_within(velocity, min, max, config);
+    }
+
     fn animate_sequence(&mut self, sequence: AnimationSequence<T>) {
-        if let Some(first_step) = sequence.steps().first() {
-            let mut state = self.write();
-            (*state).animate_to(first_step.target, (*first_step.config).clone());
-            state.sequence = Some(sequence.into());
-        }
+        (*self.write()).animate_sequence(sequence);
     }
 
     fn animate_keyframes(&mut self, animation: KeyframeAnimation<T>) {
         (*self.write()).animate_keyframes(animation);
     }
 
+    fn animate_track(&mut self, track: Arc<KeyframeTrack<T>>) {
+        (*self.write()).animate_track(track);
+    }
+
     fn update(&mut self, dt: f32) -> bool {
         (*self.write()).update(dt)
     }
@@ -62,7 +88,43 @@ impl<T: Animatable + Send + 'static> AnimationManager<T> for Signal<Motion<T>> {
         (*self.write()).stop();
     }
 
+    fn pause(&mut self) {
+        (*self.write()).pause();
+    }
+
+    fn resume(&mut self) {
+        (*self.write()).resume();
+    }
+
+    fn is_paused(&self) -> bool {
+        (*self.read()).is_paused()
+    }
+
+    fn reverse_sequence(&mut self) -> bool {
+        (*self.write()).reverse_sequence()
+    }
+
+    fn seek(&mut self, progress: f32) -> bool {
+        (*self.write()).seek(progress)
+    }
+
+    fn seek_time(&mut self, time: Duration) -> bool {
+        (*self.write()).seek_time(time)
+    }
+
+    fn set_scrubbing(&mut self, scrubbing: bool) {
+        (*self.write()).set_scrubbing(scrubbing);
+    }
+
+    fn is_scrubbing(&self) -> bool {
+        (*self.read()).is_scrubbing()
+    }
+
     fn delay(&mut self, duration: Duration) {
         (*self.write()).delay(duration);
     }
+
+    fn set_playback_rate(&mut self, rate: f32) {
+        (*self.write()).set_playback_rate(rate);
+    }
 }
@@ -1,24 +1,39 @@
 use crate::Duration;
 use crate::TimeProvider;
-use crate::animations::core::Animatable;
-use crate::animations::state_machine::AnimationState;
-use crate::keyframes::KeyframeAnimation;
+use crate::animations::core::{Animatable, AnimationMode};
+use crate::animations::state_machine::{AnimationState, MIN_DELTA};
+use crate::animations::tween::Tween;
+use crate::keyframes::{KeyframeAnimation, KeyframeDirection, KeyframeTrack};
 use crate::pool::{ConfigHandle, SpringIntegratorHandle, global};
 use crate::prelude::AnimationConfig;
+use crate::scheduler::SchedulerLink;
 use crate::sequence::AnimationSequence;
 use std::sync::Arc;
 
+/// Upper bound on simulated time, so looping animations still produce a finite trajectory
+pub const MAX_SIMULATION_TIME: Duration = Duration::from_secs(60);
+
+/// A sampled animation trajectory: `(time since start, value)` pairs
+pub type Trajectory<T> = Vec<(Duration, T)>;
+
 #[derive(Clone)]
 pub struct Motion<T: Animatable + Send + 'static> {
     pub initial: T,
     pub current: T,
     pub target: T,
     pub velocity: T,
+    /// Velocity the current animation started with
+    pub initial_velocity: T,
     pub running: bool,
     pub elapsed: Duration,
     pub delay_elapsed: Duration,
-    pub current_loop: u8,
+    /// Passes completed by the current loop, saturating at `u32::MAX`
+    pub current_loop: u32,
     pub reverse: bool,
+    /// Whether the active animation is frozen until [`Motion::resume`]
+    pub paused: bool,
+    /// Whether animations are driven only by seek calls instead of frame updates
+    pub scrubbing: bool,
 
     // Optimized components (now the primary implementation)
     /// State machine for efficient animation dispatch
@@ -29,8 +44,14 @@ pub struct Motion<T: Animatable + Send + 'static> {
     spring_integrator_handle: Option<SpringIntegratorHandle>,
     /// Current sequence being animated (if any)
     pub sequence: Option<Arc<AnimationSequence<T>>>,
-    /// Current keyframe animation (if any)
-    pub keyframe_animation: Option<Arc<KeyframeAnimation<T>>>,
+    /// Compiled track of the current keyframe animation (if any)
+    pub keyframe_animation: Option<Arc<KeyframeTrack<T>>>,
+    /// Value the current sequence started from
+    sequence_origin: T,
+    /// Link to the frame scheduler driving this motion (if any)
+    scheduler: Option<SchedulerLink>,
+    /// Bounds of the current decay animation (if any)
+    decay_bounds: Option<(T, T)>,
 
     // Internal value cache: (value, frame_time)
     value_cache: Option<(T, f32)>,
@@ -60,11 +81,14 @@ impl<T: Animatable + Send + 'static> Motion<T> {
             current: initial,
             target: initial,
             velocity: T::zero(),
+            initial_velocity: T::zero(),
             running: false,
             elapsed: Duration::default(),
             delay_elapsed: Duration::default(),
             current_loop: 0,
             reverse: false,
+            paused: false,
+            scrubbing: false,
 
             // Optimized components
             animation_state: AnimationState::new_idle(),
@@ -72,20 +96,121 @@ impl<T: Animatable + Send + 'static> Motion<T> {
             spring_integrator_handle: None,
             sequence: None,
             keyframe_animation: None,
+            sequence_origin: initial,
+            scheduler: None,
+            decay_bounds: None,
 
             value_cache: None,
         }
     }
 
+    /// Attaches a frame scheduler slot that is woken whenever an animation starts
+    pub fn with_scheduler(mut self, link: SchedulerLink) -> Self {
+        self.scheduler = Some(link);
+        self
+    }
+
+    /// Starts animating towards `target`
+    ///
+    /// When this interrupts a running animation and the config
+    /// [inherits velocity](AnimationConfig::with_inherit_velocity), the current velocity
+    /// carries over into the new animation.
     pub fn animate_to(&mut self, target: T, config: AnimationConfig) {
+        self.start(target, config, None);
+    }
+
+    /// Starts animating towards `target` with an explicit initial velocity
+    ///
+    /// Seeds springs with the velocity measured from e.g. a fling gesture, in units per
+    /// second. Tweens blend out of it, see [`AnimationConfig::with_inherit_velocity`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::motion::Motion;
+    /// use dioxus_motion::prelude::*;
+    ///
+    /// let mut motion = Motion::new(0.0f32);
+    /// motion.animate_to_with_velocity(
+    ///     0.0,
+    ///     AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+    ///     800.0, // Released while moving right
+    /// );
+    /// motion.update(1.0 / 60.0);
+    /// assert!(motion.current > 0.0);
+    /// ```
+    pub fn animate_to_with_velocity(&mut self, target: T, config: AnimationConfig, velocity: T) {
+        self.start(target, config, Some(velocity));
+    }
+
+    /// Starts a fling that decelerates from `velocity` until it comes to rest
+    ///
+    /// With [`AnimationMode::Decay`] the resting point is computed from the velocity and
+    /// becomes the new [`target`](Motion::target). Other modes animate back to the current
+    /// value, starting with `velocity`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::motion::Motion;
+    /// use dioxus_motion::prelude::*;
+    ///
+    /// let mut motion = Motion::new(0.0f32);
+    /// motion.animate_decay(1000.0, AnimationConfig::new(AnimationMode::Decay(Decay::default())));
+    /// assert!((motion.target - 500.0).abs() < 1.0);
+    /// ```
+    pub fn animate_decay(&mut self, velocity: T, config: AnimationConfig) {
+        self.start(self.current, config, Some(velocity));
+    }
+
+    /// Starts a fling like [`Motion::animate_decay`] that stays between `min` and `max`
+    ///
+    /// When the value crosses a bound, the decay's
+    /// [`boundary_spring`](crate::prelude::Decay::boundary_spring) takes over with the
+    /// current velocity and settles on the bound, like an overscrolled list. Bounds are
+    /// applied per component through [`Animatable::clamp_between`]; components still within
+    /// bounds settle on their resting point.
+    ///
+    /// Custom [`Animatable`] types must override [`Animatable::clamp_between`] for the bounds
+    /// to take effect; with the default implementation they are ignored and this behaves like
+    /// [`Motion::animate_decay`].
+    pub fn animate_decay_within(&mut self, velocity: T, min: T, max: T, config: AnimationConfig) {
+        self.animate_decay(velocity, config);
+        self.decay_bounds = Some((min, max));
+    }
+
+    /// Gets the bounds of the current decay animation
+    pub(crate) fn decay_bounds(&self) -> Option<(T, T)> {
+        self.decay_bounds
+    }
+
+    /// Resets the motion state and starts a single animation
+    ///
+    /// Uses the explicit velocity if given, otherwise the velocity of an interrupted
+    /// animation when the config inherits it, otherwise the spring's or decay's own
+    /// `velocity` towards the target. Decays replace the target with their resting point.
+    fn start(&mut self, target: T, config: AnimationConfig, velocity: Option<T>) {
+        let inherited = (self.animation_state.is_active() && config.inherits_velocity())
+            .then_some(self.velocity);
+        let velocity = velocity.or(inherited).unwrap_or_else(|| match config.mode {
+            AnimationMode::Spring(spring) => spring.velocity_towards(self.current, target),
+            AnimationMode::Decay(decay) => decay.velocity_towards(self.current, target),
+            AnimationMode::Tween(_) => T::zero(),
+        });
+        let target = match config.mode {
+            AnimationMode::Decay(decay) => self.current + decay.resting_offset(velocity),
+            _ => target,
+        };
+
         self.value_cache = None;
         self.sequence = None;
+        self.decay_bounds = None;
         self.initial = self.current;
         self.target = target;
         self.running = true;
+        self.paused = false;
         self.elapsed = Duration::default();
         self.delay_elapsed = Duration::default();
-        self.velocity = T::zero();
+        self.velocity = velocity;
+        self.initial_velocity = velocity;
         self.current_loop = 0;
 
         // Update config handle
@@ -94,9 +219,10 @@ impl<T: Animatable + Send + 'static> Motion<T> {
         });
 
         // Set up spring integrator if needed
+        // Decays need one too for the spring that settles them on a bound
         if matches!(
             config.mode,
-            crate::animations::core::AnimationMode::Spring(_)
+            AnimationMode::Spring(_) | AnimationMode::Decay(_)
         ) && self.spring_integrator_handle.is_none()
         {
             self.spring_integrator_handle = self.try_get_spring_integrator();
@@ -104,33 +230,64 @@ impl<T: Animatable + Send + 'static> Motion<T> {
 
         // Set up state machine for running animation
         self.animation_state = AnimationState::new_running(config.mode, self.config_handle.clone());
+        self.wake_scheduler();
     }
 
+    /// Plays the steps of a sequence one after another
+    ///
+    /// Leading call steps run right away; a sequence made only of call steps completes
+    /// without animating.
     pub fn animate_sequence(&mut self, sequence: AnimationSequence<T>) {
         self.value_cache = None;
-        if let Some(first_step) = sequence.steps().first() {
-            let first_config = (*first_step.config).clone();
-            self.animate_to(first_step.target, first_config);
-            let new_sequence = sequence.clone();
-            new_sequence.reset(); // Reset to first step
-            self.sequence = Some(Arc::new(new_sequence.clone()));
-
-            // Set up state machine for sequence animation
-            self.animation_state =
-                AnimationState::new_sequence(Arc::new(new_sequence), self.config_handle.clone());
+        if sequence.steps().is_empty() {
+            return;
         }
+
+        sequence.reset(); // Reset to first step
+        let Some(first_step) = sequence.begin_step() else {
+            sequence.execute_completion();
+            return;
+        };
+        self.sequence_origin = self.current;
+        let first_config = (*first_step.config).clone();
+        let target = sequence.current_step_target(self.current, self.current);
+        self.animate_to(target, first_config);
+        let new_sequence = Arc::new(sequence);
+        self.sequence = Some(new_sequence.clone());
+
+        // Set up state machine for sequence animation
+        self.animation_state =
+            AnimationState::new_sequence(new_sequence, self.config_handle.clone());
     }
 
+    /// Plays a keyframe animation with its own loop mode, delay, direction and callback
     pub fn animate_keyframes(&mut self, animation: KeyframeAnimation<T>) {
+        self.animate_track(Arc::new(animation.track()));
+    }
+
+    /// Plays a compiled keyframe track with the loop mode, delay, direction and callback
+    /// of the animation it was compiled from
+    pub fn animate_track(&mut self, track: Arc<KeyframeTrack<T>>) {
         self.value_cache = None;
-        self.keyframe_animation = Some(Arc::new(animation.clone()));
+        self.sequence = None;
+        self.keyframe_animation = Some(track.clone());
         self.running = true;
+        self.paused = false;
         self.elapsed = Duration::default();
+        self.delay_elapsed = Duration::default();
         self.velocity = T::zero();
+        self.current_loop = 0;
+        self.reverse = track.direction() == KeyframeDirection::Reverse;
+
+        // Replace whatever config the previous animation left in the pool
+        let config = track.config();
+        global::modify_config(&self.config_handle, |pooled_config| {
+            *pooled_config = config;
+        });
 
         // Set up state machine for keyframe animation
-        self.animation_state =
-            AnimationState::new_keyframes(Arc::new(animation), self.config_handle.clone());
+        self.animation_state = AnimationState::new_keyframes(track, self.config_handle.clone());
+        self.wake_scheduler();
     }
 
     pub fn get_value(&self) -> T {
@@ -148,8 +305,119 @@ impl<T: Animatable + Send + 'static> Motion<T> {
         self.current
     }
 
+    /// Returns `true` while an animation is actively advancing
+    ///
+    /// A paused or scrubbed animation is not running; see [`Motion::is_paused`]
+    /// and [`Motion::is_scrubbing`].
     pub fn is_running(&self) -> bool {
-        self.animation_state.is_active()
+        self.animation_state.is_active() && !self.paused && !self.scrubbing
+    }
+
+    /// Freezes the active animation in place
+    ///
+    /// Elapsed time, delay progress, velocity, loop counters and the current sequence step
+    /// are kept, so [`Motion::resume`] continues exactly where the animation left off.
+    /// Does nothing when no animation is active.
+    pub fn pause(&mut self) {
+        if self.animation_state.is_active() {
+            self.paused = true;
+        }
+    }
+
+    /// Continues a paused animation from where it was frozen
+    pub fn resume(&mut self) {
+        if self.paused {
+            self.paused = false;
+            self.value_cache = None;
+            self.wake_scheduler();
+        }
+    }
+
+    /// Turns the playing sequence around so it heads back through its steps
+    ///
+    /// The current step heads back from the current value to where it started; a tween
+    /// step keeps its easing and takes as long as it has played so far. The earlier steps
+    /// then play in reverse order back to the sequence's origin. Reversing again plays
+    /// forwards towards the end. Returns `false` if no sequence is playing.
+    pub fn reverse_sequence(&mut self) -> bool {
+        let Some(sequence) = self.sequence.clone() else {
+            return false;
+        };
+
+        // Replaying the eased curve from the current value avoids a jump for non-linear easings
+        let retrace = match sequence.current_step_data().map(|step| &step.config.mode) {
+            Some(AnimationMode::Tween(tween)) => Some(Tween {
+                duration: self.elapsed,
+                easing: tween.easing.clone(),
+            }),
+            _ => None,
+        };
+        sequence.set_reversed(!sequence.is_reversed());
+        self.value_cache = None;
+        if let Some(tween) = retrace {
+            self.animation_state = AnimationState::new_running(
+                AnimationMode::Tween(tween),
+                self.config_handle.clone(),
+            );
+        }
+        self.target = sequence.current_step_target(self.current, self.sequence_origin);
+        self.initial = self.current;
+        self.elapsed = Duration::default();
+        self.current_loop = 0;
+        self.running = true;
+        self.wake_scheduler();
+        true
+    }
+
+    /// Returns `true` if an animation is frozen by [`Motion::pause`]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Jumps the active time-based animation to `progress` (0.0 to 1.0) and renders that frame
+    ///
+    /// Works for tweens, keyframe animations, analytic springs (which last until they
+    /// settle) and sequences made of those; delays are part of the timeline.
+    /// Returns `false` if nothing seekable is active. A running animation keeps playing
+    /// from the new position.
+    pub fn seek(&mut self, progress: f32) -> bool {
+        self.animation_state
+            .seekable_duration(self)
+            .is_some_and(|duration| self.seek_time(duration.mul_f32(progress.clamp(0.0, 1.0))))
+    }
+
+    /// Jumps the active time-based animation to `time` since its start
+    ///
+    /// See [`Motion::seek`] for which animations can be seeked.
+    pub fn seek_time(&mut self, time: Duration) -> bool {
+        self.value_cache = None;
+        let mut state = std::mem::replace(&mut self.animation_state, AnimationState::new_idle());
+        let seeked = state.seek(time, self);
+        self.animation_state = state;
+        seeked
+    }
+
+    /// Enables or disables scrub mode
+    ///
+    /// While scrubbing, frame updates are ignored and animations only move through
+    /// [`Motion::seek`] and [`Motion::seek_time`], e.g. from a slider or scroll position.
+    /// The mode persists across new `animate_*` calls until disabled.
+    pub fn set_scrubbing(&mut self, scrubbing: bool) {
+        let was_scrubbing = self.scrubbing;
+        self.scrubbing = scrubbing;
+        if was_scrubbing && !scrubbing {
+            self.wake_scheduler();
+        }
+    }
+
+    /// Returns `true` if the motion is in scrub mode
+    pub fn is_scrubbing(&self) -> bool {
+        self.scrubbing
+    }
+
+    /// Gets the value the current sequence started from
+    pub(crate) fn sequence_origin(&self) -> T {
+        self.sequence_origin
     }
 
     pub fn reset(&mut self) {
@@ -162,6 +430,7 @@ impl<T: Animatable + Send + 'static> Motion<T> {
     pub fn stop(&mut self) {
         self.value_cache = None;
         self.running = false;
+        self.paused = false;
         self.current_loop = 0;
         self.velocity = T::zero();
         self.sequence = None;
@@ -183,6 +452,19 @@ impl<T: Animatable + Send + 'static> Motion<T> {
         });
     }
 
+    /// Changes the playback rate of the active animation
+    ///
+    /// See [`AnimationConfig::with_playback_rate`]. Flipping the sign mid-flight
+    /// reverses the animation from its current position.
+    pub fn set_playback_rate(&mut self, rate: f32) {
+        self.value_cache = None;
+
+        // Update config handle
+        global::modify_config(&self.config_handle, |pooled_config| {
+            pooled_config.playback_rate = Some(rate);
+        });
+    }
+
     /// Gets the effective epsilon threshold for this animation
     /// Uses the configured epsilon if present, otherwise falls back to the type's default
     pub fn get_epsilon(&self) -> f32 {
@@ -194,6 +476,11 @@ impl<T: Animatable + Send + 'static> Motion<T> {
     }
 
     pub fn update(&mut self, dt: f32) -> bool {
+        // Paused and scrubbed animations keep their state untouched
+        if self.paused || self.scrubbing {
+            return false;
+        }
+
         // Invalidate value cache on update
         self.value_cache = None;
 
@@ -205,6 +492,70 @@ impl<T: Animatable + Send + 'static> Motion<T> {
         result
     }
 
+    /// Runs a single animation offline and returns every sampled value
+    ///
+    /// The animation is stepped with a fixed `dt` (in seconds) through the same state machine
+    /// used at runtime until it completes, or until [`MAX_SIMULATION_TIME`] for looping
+    /// animations. The first sample is the initial value at time zero. `dt` is clamped to the
+    /// engine's minimum frame delta of 1/240 s.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::motion::Motion;
+    /// use dioxus_motion::prelude::*;
+    ///
+    /// let trajectory = Motion::simulate(
+    ///     0.0f32,
+    ///     100.0,
+    ///     AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+    ///     1.0 / 60.0,
+    /// );
+    /// assert_eq!(trajectory.last().map(|(_, value)| *value), Some(100.0));
+    /// ```
+    pub fn simulate(initial: T, target: T, config: AnimationConfig, dt: f32) -> Trajectory<T> {
+        let mut motion = Self::new(initial);
+        motion.animate_to(target, config);
+        motion.run_to_completion(dt)
+    }
+
+    /// Runs an animation sequence offline starting from `initial`
+    ///
+    /// See [`Motion::simulate`] for sampling details.
+    pub fn simulate_sequence(initial: T, sequence: AnimationSequence<T>, dt: f32) -> Trajectory<T> {
+        let mut motion = Self::new(initial);
+        motion.animate_sequence(sequence);
+        motion.run_to_completion(dt)
+    }
+
+    /// Runs a keyframe animation offline starting from its first keyframe
+    ///
+    /// See [`Motion::simulate`] for sampling details.
+    pub fn simulate_keyframes(animation: KeyframeAnimation<T>, dt: f32) -> Trajectory<T> {
+        let initial = animation
+            .keyframes
+            .first()
+            .map_or_else(T::default, |keyframe| keyframe.value);
+        let mut motion = Self::new(initial);
+        motion.animate_keyframes(animation);
+        motion.run_to_completion(dt)
+    }
+
+    /// Steps the motion with a fixed delta until it stops running
+    fn run_to_completion(&mut self, dt: f32) -> Trajectory<T> {
+        let dt = dt.max(MIN_DELTA);
+        let step = Duration::from_secs_f32(dt);
+        let mut time = Duration::ZERO;
+        let mut trajectory = vec![(time, self.current)];
+
+        while self.is_running() && time < MAX_SIMULATION_TIME {
+            self.update(dt);
+            time += step;
+            trajectory.push((time, self.current));
+        }
+
+        trajectory
+    }
+
     /// Gets the current config handle for optimization purposes
     pub fn config_handle(&self) -> &ConfigHandle {
         &self.config_handle
@@ -225,6 +576,13 @@ impl<T: Animatable + Send + 'static> Motion<T> {
         }
     }
 
+    /// Asks the attached frame scheduler to tick this motion again
+    fn wake_scheduler(&self) {
+        if let Some(link) = &self.scheduler {
+            link.wake();
+        }
+    }
+
     /// Tries to get a spring integrator handle (now always available)
     fn try_get_spring_integrator(&self) -> Option<SpringIntegratorHandle> {
         Some(crate::pool::integrator::get_integrator::<T>())
@@ -250,10 +608,12 @@ mod tests {
     #![allow(clippy::unwrap_used)]
     use super::*;
     use crate::animations::core::AnimationMode;
+    use crate::animations::decay::Decay;
     use crate::animations::spring::Spring;
     use crate::keyframes::KeyframeAnimation;
-    use crate::prelude::Tween;
+    use crate::prelude::{LoopMode, Tween};
     use crate::sequence::{AnimationSequence, AnimationStep};
+    use crate::test_utils::{EventLog, FRAME, frames_to_finish, linear_tween};
 
     #[test]
     fn test_motion_new_with_optimizations() {
@@ -331,16 +691,8 @@ mod tests {
         let mut motion = Motion::new(0.0f32);
 
         let steps = vec![
-            AnimationStep {
-                target: 50.0,
-                config: Arc::new(AnimationConfig::default()),
-                predicted_next: None,
-            },
-            AnimationStep {
-                target: 100.0,
-                config: Arc::new(AnimationConfig::default()),
-                predicted_next: None,
-            },
+            AnimationStep::new(50.0, AnimationConfig::default()),
+            AnimationStep::new(100.0, AnimationConfig::default()),
         ];
 
         let sequence = AnimationSequence::from_steps(steps);
@@ -527,6 +879,1162 @@ mod tests {
         assert!(!stats.value_cache_active);
     }
 
+    #[test]
+    fn test_simulate_tween_trajectory() {
+        let dt = 1.0 / 60.0;
+        let trajectory = Motion::simulate(
+            0.0f32,
+            100.0,
+            AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(100)))),
+            dt,
+        );
+
+        assert_eq!(trajectory[0], (Duration::ZERO, 0.0));
+        assert_eq!(trajectory.last().unwrap().1, 100.0);
+        // 100ms at 60fps settles within 6-7 frames
+        assert!((7..=8).contains(&trajectory.len()));
+        assert!(trajectory.windows(2).all(|w| w[1].1 >= w[0].1));
+        assert!(
+            trajectory
+                .windows(2)
+                .all(|w| w[1].0 - w[0].0 == Duration::from_secs_f32(dt))
+        );
+    }
+
+    #[test]
+    fn test_simulate_spring_is_deterministic() {
+        let config = AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+        let first = Motion::simulate(0.0f32, 100.0, config.clone(), 1.0 / 60.0);
+        let second = Motion::simulate(0.0f32, 100.0, config, 1.0 / 60.0);
+
+        assert_eq!(first, second);
+        assert_eq!(first.last().unwrap().1, 100.0);
+        // Default spring is underdamped and should overshoot
+        assert!(first.iter().any(|(_, value)| *value > 100.0));
+    }
+
+    #[test]
+    fn test_simulate_sequence_visits_every_step() {
+        let tween =
+            AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(50))));
+        let sequence = AnimationSequence::new()
+            .then(50.0f32, tween.clone())
+            .then(10.0, tween);
+
+        let trajectory = sequence.simulate(0.0, 1.0 / 60.0);
+
+        assert!(trajectory.iter().any(|(_, value)| *value == 50.0));
+        assert_eq!(trajectory.last().unwrap().1, 10.0);
+    }
+
+    #[test]
+    fn test_simulate_keyframes() {
+        let animation = KeyframeAnimation::new(Duration::from_millis(200))
+            .add_keyframe(0.0f32, 0.0, None)
+            .unwrap()
+            .add_keyframe(100.0, 0.5, None)
+            .unwrap()
+            .add_keyframe(0.0, 1.0, None)
+            .unwrap();
+
+        let trajectory = animation.simulate(0.01);
+
+        assert_eq!(trajectory[0].1, 0.0);
+        let (peak_time, peak) = trajectory
+            .iter()
+            .copied()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap();
+        assert!((peak - 100.0).abs() < 1e-3);
+        assert!((peak_time.as_secs_f32() - 0.1).abs() < 0.011);
+        assert_eq!(trajectory.last().unwrap().1, 0.0);
+    }
+
+    #[test]
+    fn test_simulate_infinite_loop_is_bounded() {
+        let trajectory = Motion::simulate(
+            0.0f32,
+            1.0,
+            AnimationConfig::new(AnimationMode::Tween(Tween::default()))
+                .with_loop(crate::prelude::LoopMode::Infinite),
+            0.1,
+        );
+
+        assert!(trajectory.last().unwrap().0 >= MAX_SIMULATION_TIME);
+    }
+
+    /// Steps `motion` for `frames` frames, freezing it for 30 frames after `pause_at`
+    fn run_with_pause(mut motion: Motion<f32>, frames: usize, pause_at: usize) -> Vec<f32> {
+        let mut values = Vec::new();
+        for frame in 0..frames {
+            if frame == pause_at {
+                motion.pause();
+                let frozen = (motion.current, motion.velocity, motion.elapsed);
+                for _ in 0..30 {
+                    assert!(!motion.update(1.0 / 60.0));
+                }
+                assert_eq!(frozen, (motion.current, motion.velocity, motion.elapsed));
+                motion.resume();
+            }
+            motion.update(1.0 / 60.0);
+            values.push(motion.current);
+        }
+        values
+    }
+
+    #[test]
+    fn test_pause_resume_tween_continues_where_it_left_off() {
+        let start = || {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(
+                100.0,
+                AnimationConfig::new(AnimationMode::Tween(Tween::default()))
+                    .with_delay(Duration::from_millis(50)),
+            );
+            motion
+        };
+
+        assert_eq!(
+            run_with_pause(start(), 40, 2),
+            run_with_pause(start(), 40, usize::MAX)
+        );
+        assert_eq!(
+            run_with_pause(start(), 40, 10),
+            run_with_pause(start(), 40, usize::MAX)
+        );
+    }
+
+    #[test]
+    fn test_pause_resume_spring_preserves_velocity() {
+        let start = || {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(
+                100.0,
+                AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+            );
+            motion
+        };
+
+        let mut motion = start();
+        for _ in 0..5 {
+            motion.update(1.0 / 60.0);
+        }
+        let velocity = motion.velocity;
+        motion.pause();
+        assert!(motion.is_paused());
+        assert!(!motion.is_running());
+        assert_eq!(motion.velocity, velocity);
+        assert!(velocity > 0.0);
+
+        assert_eq!(
+            run_with_pause(start(), 120, 5),
+            run_with_pause(start(), 120, usize::MAX)
+        );
+    }
+
+    #[test]
+    fn test_pause_resume_sequence_keeps_step() {
+        let start = || {
+            let tween =
+                AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(100))));
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_sequence(
+                AnimationSequence::new()
+                    .then(50.0, tween.clone())
+                    .then(100.0, tween),
+            );
+            motion
+        };
+
+        let mut motion = start();
+        for _ in 0..8 {
+            motion.update(1.0 / 60.0);
+        }
+        let step = motion.sequence.as_ref().unwrap().current_step();
+        assert_eq!(step, 1);
+        motion.pause();
+        motion.update(1.0 / 60.0);
+        assert_eq!(motion.sequence.as_ref().unwrap().current_step(), step);
+
+        assert_eq!(
+            run_with_pause(start(), 20, 8),
+            run_with_pause(start(), 20, usize::MAX)
+        );
+    }
+
+    #[test]
+    fn test_pause_resume_keyframes_and_loops() {
+        let keyframes = || {
+            let animation = KeyframeAnimation::new(Duration::from_millis(200))
+                .add_keyframe(0.0f32, 0.0, None)
+                .unwrap()
+                .add_keyframe(100.0, 1.0, None)
+                .unwrap();
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_keyframes(animation);
+            motion
+        };
+        assert_eq!(
+            run_with_pause(keyframes(), 15, 4),
+            run_with_pause(keyframes(), 15, usize::MAX)
+        );
+
+        let looping = || {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(
+                1.0,
+                AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(50))))
+                    .with_loop(crate::prelude::LoopMode::Times(3)),
+            );
+            motion
+        };
+        assert_eq!(
+            run_with_pause(looping(), 12, 5),
+            run_with_pause(looping(), 12, usize::MAX)
+        );
+    }
+
+    #[test]
+    fn test_pause_without_animation_and_stop_clears_pause() {
+        let mut motion = Motion::new(0.0f32);
+        motion.pause();
+        assert!(!motion.is_paused());
+
+        motion.animate_to(1.0, AnimationConfig::default());
+        motion.pause();
+        assert!(motion.is_paused());
+        motion.stop();
+        assert!(!motion.is_paused());
+
+        motion.animate_to(1.0, AnimationConfig::default());
+        motion.pause();
+        motion.animate_to(2.0, AnimationConfig::default());
+        assert!(!motion.is_paused());
+        assert!(motion.is_running());
+    }
+
+    #[test]
+    fn test_seek_tween_with_delay() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            linear_tween(200).with_delay(Duration::from_millis(200)),
+        );
+
+        // First half of the timeline is the delay
+        assert!(motion.seek(0.25));
+        assert_eq!(motion.current, 0.0);
+        assert!(motion.seek(0.75));
+        assert!((motion.current - 50.0).abs() < 0.01);
+
+        assert!(motion.seek_time(Duration::from_millis(300)));
+        assert!((motion.current - 50.0).abs() < 0.01);
+
+        // Still running: playback continues from the seeked point
+        assert!(motion.is_running());
+        motion.update(0.05);
+        assert!((motion.current - 75.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_seek_keyframes() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_keyframes(
+            KeyframeAnimation::new(Duration::from_secs(1))
+                .add_keyframe(0.0f32, 0.0, None)
+                .unwrap()
+                .add_keyframe(10.0, 0.5, None)
+                .unwrap()
+                .add_keyframe(30.0, 1.0, None)
+                .unwrap(),
+        );
+
+        assert!(motion.seek(0.25));
+        assert!((motion.current - 5.0).abs() < 0.01);
+        assert!(motion.seek(0.75));
+        assert!((motion.current - 20.0).abs() < 0.01);
+        assert_eq!(motion.elapsed, Duration::from_millis(750));
+    }
+
+    #[test]
+    fn test_seek_tween_sequence() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(10.0, linear_tween(100))
+                .then(30.0, linear_tween(100))
+                .then(0.0, linear_tween(200)),
+        );
+
+        assert!(motion.seek(0.75));
+        assert_eq!(motion.sequence.as_ref().unwrap().current_step(), 2);
+        assert!((motion.current - 15.0).abs() < 0.01);
+
+        // Seeking backwards restores earlier steps and their start values
+        assert!(motion.seek_time(Duration::from_millis(150)));
+        assert_eq!(motion.sequence.as_ref().unwrap().current_step(), 1);
+        assert!((motion.current - 20.0).abs() < 0.01);
+        assert!(motion.seek(0.0));
+        assert_eq!(motion.current, 0.0);
+
+        // Playback resumes from the seeked step through the rest of the sequence
+        motion.seek(0.5);
+        while motion.is_running() {
+            motion.update(1.0 / 60.0);
+        }
+        assert_eq!(motion.current, 0.0);
+        assert_eq!(motion.sequence.as_ref().map(|s| s.current_step()), None);
+    }
+
+    #[test]
+    fn test_sequence_step_hooks_call_and_wait_steps() {
+        let log = EventLog::default();
+        let sequence = AnimationSequence::new()
+            .then(100.0f32, linear_tween(100))
+            .wait(Duration::from_millis(100))
+            .call(log.recorder("call"))
+            .then(0.0, linear_tween(100))
+            .on_step_start(log.step("start"))
+            .on_step_complete(log.step("complete"))
+            .on_complete(log.recorder("done"));
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(sequence.clone());
+        let mut held = 0;
+        let mut frames = 0;
+        while motion.is_running() && frames < 1000 {
+            motion.update(FRAME);
+            frames += 1;
+            if motion.sequence.as_ref().map(|s| s.current_step()) == Some(1) {
+                assert_eq!(motion.current, 100.0);
+                held += 1;
+            }
+        }
+        assert_eq!(held, 7);
+        assert_eq!(motion.current, 0.0);
+
+        let expected = [
+            "start 0",
+            "complete 0",
+            "start 1",
+            "complete 1",
+            "start 2",
+            "call",
+            "complete 2",
+            "start 3",
+            "complete 3",
+            "done",
+        ]
+        .map(String::from);
+        assert_eq!(log.events(), expected);
+
+        // Callbacks survive the clone and run again on replay
+        log.clear();
+        motion.animate_sequence(sequence);
+        frames_to_finish(&mut motion);
+        assert_eq!(log.events(), expected);
+    }
+
+    #[test]
+    fn test_sequence_of_call_steps_completes_without_animating() {
+        use std::sync::Mutex;
+
+        let calls = Arc::new(Mutex::new(0));
+        let (first, second, done) = (calls.clone(), calls.clone(), calls.clone());
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .call(move || *first.lock().unwrap() += 1)
+                .call(move || *second.lock().unwrap() += 10)
+                .on_complete(move || *done.lock().unwrap() += 100),
+        );
+        assert_eq!(*calls.lock().unwrap(), 111);
+        assert!(!motion.is_running());
+        assert!(motion.sequence.is_none());
+    }
+
+    #[test]
+    fn test_seek_sequence_with_wait_step() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(100.0, linear_tween(100))
+                .wait(Duration::from_millis(100))
+                .then(0.0, linear_tween(100)),
+        );
+        assert_eq!(
+            motion.animation_state.seekable_duration(&motion),
+            Some(Duration::from_millis(300))
+        );
+        assert!(motion.seek_time(Duration::from_millis(150)));
+        assert_eq!(motion.current, 100.0);
+        assert!(motion.seek_time(Duration::from_millis(250)));
+        assert!((motion.current - 50.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_long_generated_sequence_visits_every_step() {
+        const STEPS: usize = 1000;
+        let frame = Tween::new(Duration::from_secs_f32(FRAME));
+        let sequence = (1..=STEPS).fold(AnimationSequence::new(), |sequence, i| {
+            sequence.then(
+                i as f32,
+                AnimationConfig::new(AnimationMode::Tween(frame.clone())),
+            )
+        });
+        assert_eq!(sequence.total_steps(), STEPS);
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(sequence);
+        let mut furthest = 0;
+        let mut frames = 0;
+        while motion.is_running() && frames < 2 * STEPS {
+            if let Some(sequence) = &motion.sequence {
+                assert!(sequence.current_step() >= furthest);
+                furthest = sequence.current_step();
+            }
+            motion.update(FRAME);
+            frames += 1;
+        }
+        assert_eq!(furthest, STEPS - 1);
+        assert_eq!(motion.current, STEPS as f32);
+
+        // Seeking far past step 255 lands on the right step
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence((1..=STEPS).fold(AnimationSequence::new(), |sequence, i| {
+            sequence.then(i as f32, linear_tween(10))
+        }));
+        assert!(motion.seek_time(Duration::from_millis(7005)));
+        assert_eq!(motion.sequence.as_ref().unwrap().current_step(), 700);
+        assert!((motion.current - 700.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_loop_counts_past_255() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            1.0,
+            AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_secs_f32(
+                FRAME,
+            ))))
+            .with_loop(LoopMode::Times(300)),
+        );
+        assert_eq!(frames_to_finish(&mut motion), 300);
+        assert_eq!(motion.current_loop, 0);
+
+        let mut keyframes = Motion::new(0.0f32);
+        keyframes.animate_keyframes(keyframe_track().with_loop(LoopMode::AlternateTimes(200)));
+        let mut frames = 0;
+        while keyframes.is_running() && frames < 10_000 {
+            keyframes.update(FRAME);
+            frames += 1;
+        }
+        assert_eq!(frames, 200 * 2 * 8);
+        assert_eq!(keyframes.current, 0.0);
+    }
+
+    #[test]
+    fn test_seek_unsupported_animations() {
+        let mut motion = Motion::new(0.0f32);
+        assert!(!motion.seek(0.5));
+
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+        assert!(!motion.seek(0.5));
+        assert_eq!(motion.current, 0.0);
+    }
+
+    #[test]
+    fn test_scrub_mode_is_driven_only_by_seek() {
+        let mut motion = Motion::new(0.0f32);
+        motion.set_scrubbing(true);
+        motion.animate_to(100.0, linear_tween(100));
+
+        assert!(!motion.is_running());
+        assert!(!motion.update(0.05));
+        assert_eq!(motion.current, 0.0);
+
+        // Seeking to the end does not complete the animation, so it can be scrubbed back
+        assert!(motion.seek(1.0));
+        assert_eq!(motion.current, 100.0);
+        assert!(motion.seek(0.3));
+        assert!((motion.current - 30.0).abs() < 0.01);
+
+        motion.set_scrubbing(false);
+        assert!(motion.is_running());
+        while motion.is_running() {
+            motion.update(1.0 / 60.0);
+        }
+        assert_eq!(motion.current, 100.0);
+    }
+
+    #[test]
+    fn test_playback_rate_scales_tween_and_keyframes() {
+        let mut normal = Motion::new(0.0f32);
+        normal.animate_to(1.0, linear_tween(125));
+        let mut fast = Motion::new(0.0f32);
+        fast.animate_to(1.0, linear_tween(125).with_playback_rate(2.0));
+        assert_eq!(frames_to_finish(&mut normal), 8);
+        assert_eq!(frames_to_finish(&mut fast), 4);
+
+        // One keyframe animation reused at half speed
+        let animation = KeyframeAnimation::new(Duration::from_millis(125))
+            .add_keyframe(0.0f32, 0.0, None)
+            .unwrap()
+            .add_keyframe(1.0, 1.0, None)
+            .unwrap();
+        let mut slow = Motion::new(0.0f32);
+        slow.animate_keyframes(animation);
+        slow.set_playback_rate(0.5);
+        assert_eq!(frames_to_finish(&mut slow), 16);
+        assert_eq!(slow.current, 1.0);
+    }
+
+    #[test]
+    fn test_extreme_playback_rates_finish_without_panicking() {
+        for (rate, end) in [(1e30, 100.0), (-1e30, 0.0), (f32::INFINITY, 100.0)] {
+            let delayed = linear_tween(125).with_delay(Duration::from_millis(50));
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(100.0, delayed.with_playback_rate(rate));
+            assert!(frames_to_finish(&mut motion) <= 2, "{rate}");
+            assert_eq!(motion.current, end, "{rate}");
+
+            let mut spring = Motion::new(0.0f32);
+            spring.animate_to(
+                100.0,
+                AnimationConfig::new(AnimationMode::Spring(Spring::default()))
+                    .with_playback_rate(rate),
+            );
+            frames_to_finish(&mut spring);
+            assert_eq!(spring.current, 100.0, "{rate}");
+        }
+
+        let mut keyframes = Motion::new(0.0f32);
+        keyframes.animate_keyframes(keyframe_track());
+        keyframes.set_playback_rate(f32::NEG_INFINITY);
+        frames_to_finish(&mut keyframes);
+        assert_eq!(keyframes.current, 0.0);
+    }
+
+    #[test]
+    fn test_negative_playback_rate_plays_backwards() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, linear_tween(125).with_playback_rate(-1.0));
+
+        motion.update(FRAME);
+        assert!((motion.current - 87.5).abs() < 0.01);
+        assert_eq!(frames_to_finish(&mut motion), 7);
+        assert_eq!(motion.current, 0.0);
+
+        let mut keyframes = Motion::new(0.0f32);
+        keyframes.animate_keyframes(
+            KeyframeAnimation::new(Duration::from_millis(125))
+                .add_keyframe(0.0f32, 0.0, None)
+                .unwrap()
+                .add_keyframe(10.0, 1.0, None)
+                .unwrap(),
+        );
+        keyframes.set_playback_rate(-1.0);
+        keyframes.update(2.0 * FRAME);
+        assert!((keyframes.current - 7.5).abs() < 0.01);
+        frames_to_finish(&mut keyframes);
+        assert_eq!(keyframes.current, 0.0);
+    }
+
+    #[test]
+    fn test_flipping_playback_rate_reverses_mid_flight() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, linear_tween(125));
+        for _ in 0..5 {
+            motion.update(FRAME);
+        }
+        assert!((motion.current - 62.5).abs() < 0.01);
+
+        motion.set_playback_rate(-1.0);
+        motion.update(FRAME);
+        assert!((motion.current - 50.0).abs() < 0.01);
+        assert_eq!(frames_to_finish(&mut motion), 4);
+        assert_eq!(motion.current, 0.0);
+    }
+
+    #[test]
+    fn test_spring_playback_rate_uses_magnitude() {
+        let spring = || AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+        let mut normal = Motion::new(0.0f32);
+        normal.animate_to(100.0, spring());
+        let mut slow = Motion::new(0.0f32);
+        slow.animate_to(100.0, spring().with_playback_rate(-0.5));
+
+        normal.update(0.01);
+        slow.update(0.02);
+        assert!((normal.current - slow.current).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_analytic_spring_is_frame_rate_independent() {
+        let config = || AnimationConfig::new(AnimationMode::Spring(Spring::default().analytic()));
+        let at_half_second = |fps: u32| {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(100.0, config());
+            for _ in 0..fps / 2 {
+                motion.update(1.0 / fps as f32);
+            }
+            (motion.current, motion.velocity)
+        };
+
+        let (slow, slow_velocity) = at_half_second(30);
+        let (fast, fast_velocity) = at_half_second(120);
+        assert!((slow - fast).abs() < 0.01);
+        assert!((slow_velocity - fast_velocity).abs() < 0.05);
+
+        let trajectory = Motion::simulate(0.0f32, 100.0, config(), 1.0 / 60.0);
+        assert_eq!(trajectory.last().unwrap().1, 100.0);
+    }
+
+    #[test]
+    fn test_seek_analytic_spring() {
+        let spring = Spring::default().analytic();
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(spring))
+                .with_delay(Duration::from_millis(100)),
+        );
+
+        assert!(motion.seek_time(Duration::from_millis(50)));
+        assert_eq!(motion.current, 0.0);
+
+        assert!(motion.seek_time(Duration::from_millis(400)));
+        let (displacement, velocity) = spring.response(0.3).apply(-100.0f32, 0.0);
+        assert!((motion.current - (100.0 + displacement)).abs() < 1e-3);
+        assert!((motion.velocity - velocity).abs() < 1e-3);
+        assert!(motion.is_running());
+    }
+
+    #[test]
+    fn test_seek_progress_spans_spring_settling_time() {
+        let spring = Spring::default().analytic();
+        let config = AnimationConfig::new(AnimationMode::Spring(spring));
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, config.clone());
+
+        assert!(motion.seek(1.0));
+        assert_eq!(Some(motion.elapsed), config.total_duration_for(100.0));
+        assert!((motion.current - 100.0).abs() < 0.01);
+
+        // Sequences mixing tweens and analytic springs are seekable end to end
+        let mut sequence = Motion::new(0.0f32);
+        sequence.animate_sequence(
+            AnimationSequence::new()
+                .then(50.0, linear_tween(100))
+                .then(100.0, config),
+        );
+        assert!(sequence.seek_time(Duration::from_millis(50)));
+        assert!((sequence.current - 25.0).abs() < 0.01);
+        assert!(sequence.seek(1.0));
+        assert_eq!(sequence.sequence.as_ref().unwrap().current_step(), 1);
+        assert!((sequence.current - 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_retargeting_spring_carries_velocity() {
+        let spring = || AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, spring());
+        for _ in 0..10 {
+            motion.update(1.0 / 60.0);
+        }
+        let velocity = motion.velocity;
+        assert!(velocity > 0.0);
+
+        motion.animate_to(200.0, spring());
+        assert_eq!(motion.velocity, velocity);
+        assert_eq!(motion.initial_velocity, velocity);
+
+        // Opting out restarts from rest
+        motion.animate_to(300.0, spring().with_inherit_velocity(false));
+        assert_eq!(motion.velocity, 0.0);
+
+        // Nothing to carry once the previous animation has finished
+        let mut settled = Motion::new(0.0f32);
+        settled.animate_to(1.0, spring());
+        while settled.is_running() {
+            settled.update(1.0 / 60.0);
+        }
+        settled.animate_to(2.0, spring());
+        assert_eq!(settled.velocity, 0.0);
+    }
+
+    #[test]
+    fn test_interrupted_analytic_spring_is_continuous() {
+        let spring = || AnimationConfig::new(AnimationMode::Spring(Spring::default().analytic()));
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, spring());
+        for _ in 0..10 {
+            motion.update(1.0 / 60.0);
+        }
+        let (position, velocity) = (motion.current, motion.velocity);
+
+        motion.animate_to(-100.0, spring());
+        motion.update(0.001);
+        assert!((motion.velocity - velocity).abs() < 0.1 * velocity.abs() + 5.0);
+        assert!((motion.current - (position + velocity * 0.001)).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_tween_blends_from_inherited_velocity() {
+        let moving = || {
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to(100.0, linear_tween(125));
+            for _ in 0..4 {
+                motion.update(FRAME);
+            }
+            motion
+        };
+        let mut motion = moving();
+        assert!((motion.velocity - 800.0).abs() < 0.1);
+
+        // Tweens start from rest unless asked to blend
+        let mut from_rest = moving();
+        from_rest.animate_to(0.0, linear_tween(125));
+        assert_eq!(from_rest.velocity, 0.0);
+
+        motion.animate_to(0.0, linear_tween(125).with_inherit_velocity(true));
+        let start = motion.current;
+        motion.update(0.0005);
+        let initial_slope = (motion.current - start) / 0.0005;
+        assert!(
+            initial_slope > 0.0,
+            "keeps moving forward at first: {initial_slope}"
+        );
+
+        while motion.is_running() {
+            motion.update(FRAME);
+        }
+        assert_eq!(motion.current, 0.0);
+    }
+
+    #[test]
+    fn test_animate_to_with_velocity_seeds_spring() {
+        for spring in [Spring::default(), Spring::default().analytic()] {
+            let config = AnimationConfig::new(AnimationMode::Spring(spring));
+            let mut motion = Motion::new(0.0f32);
+            motion.animate_to_with_velocity(0.0, config.clone(), 500.0);
+            assert_eq!(motion.velocity, 500.0);
+
+            // A fling at rest overshoots in the direction of the velocity and comes back
+            let trajectory = motion.run_to_completion(1.0 / 60.0);
+            let peak = trajectory
+                .iter()
+                .map(|(_, value)| *value)
+                .fold(0.0, f32::max);
+            assert!(peak > 10.0, "{spring:?} peaked at {peak}");
+            assert_eq!(trajectory.last().unwrap().1, 0.0);
+
+            // An explicit velocity wins over the inherited one
+            motion.animate_to(100.0, config.clone());
+            motion.update(1.0 / 60.0);
+            motion.animate_to_with_velocity(100.0, config, -20.0);
+            assert_eq!(motion.velocity, -20.0);
+        }
+    }
+
+    #[test]
+    fn test_typed_velocity_for_transforms() {
+        use crate::animations::transform::Transform;
+
+        let mut motion = Motion::new(Transform::identity());
+        let velocity = Transform::new(300.0, -150.0, 0.0, 0.0);
+        motion.animate_to_with_velocity(
+            Transform::identity(),
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+            velocity,
+        );
+        motion.update(1.0 / 60.0);
+        assert!(motion.current.x > 0.0);
+        assert!(motion.current.y < 0.0);
+    }
+
+    #[test]
+    fn test_spring_velocity_field_is_honored() {
+        let spring = Spring {
+            velocity: 50.0,
+            ..Default::default()
+        };
+        let mut motion = Motion::new(100.0f32);
+        motion.animate_to(0.0, AnimationConfig::new(AnimationMode::Spring(spring)));
+        assert_eq!(motion.velocity, -50.0);
+
+        // Sequence steps start with the spring velocity too
+        let mut sequence = Motion::new(0.0f32);
+        sequence.animate_sequence(
+            AnimationSequence::new()
+                .then(10.0, linear_tween(50))
+                .then(20.0, AnimationConfig::new(AnimationMode::Spring(spring))),
+        );
+        while sequence
+            .sequence
+            .as_ref()
+            .is_some_and(|s| s.current_step() == 0)
+        {
+            sequence.update(FRAME);
+        }
+        assert_eq!(sequence.initial_velocity, 50.0);
+    }
+
+    #[test]
+    fn test_keyframes_with_cubic_bezier_segments() {
+        use crate::animations::easing::CubicBezier;
+
+        let animation = KeyframeAnimation::new(Duration::from_secs(1))
+            .add_keyframe(0.0f32, 0.0, None)
+            .unwrap()
+            .add_keyframe_with_curve(100.0, 0.5, CubicBezier::EASE)
+            .unwrap()
+            .add_keyframe(200.0, 1.0, None)
+            .unwrap();
+
+        let eased = animation.sample(0.25).unwrap();
+        assert!((eased - 100.0 * CubicBezier::EASE.ease(0.5)).abs() < 1e-3);
+        // The second segment stays linear
+        assert!((animation.sample(0.75).unwrap() - 150.0).abs() < 1e-3);
+    }
+
+    fn keyframe_track() -> KeyframeAnimation<f32> {
+        KeyframeAnimation::new(Duration::from_millis(125))
+            .add_keyframe(0.0f32, 0.0, None)
+            .unwrap()
+            .add_keyframe(100.0, 1.0, None)
+            .unwrap()
+    }
+
+    fn keyframe_values(animation: KeyframeAnimation<f32>, frames: usize) -> Vec<f32> {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_keyframes(animation);
+        (0..frames)
+            .map(|_| {
+                motion.update(FRAME);
+                motion.current
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_keyframes_loop_none_plays_once() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_keyframes(keyframe_track().with_loop(LoopMode::None));
+        assert_eq!(frames_to_finish(&mut motion), 8);
+        assert_eq!(motion.current, 100.0);
+        assert!(motion.keyframe_animation.is_none());
+    }
+
+    #[test]
+    fn test_compiled_track_plays_with_its_playback_settings() {
+        let track = Arc::new(
+            keyframe_track()
+                .with_loop(LoopMode::Times(2))
+                .with_direction(KeyframeDirection::Reverse)
+                .compile()
+                .unwrap(),
+        );
+        let mut first = Motion::new(0.0f32);
+        let mut second = Motion::new(0.0f32);
+        first.animate_track(track.clone());
+        second.animate_track(track.clone());
+        assert_eq!(Arc::strong_count(&track), 5);
+
+        first.update(FRAME);
+        assert!((first.current - 87.5).abs() < 0.01);
+        assert_eq!(frames_to_finish(&mut first), 15);
+        assert_eq!(frames_to_finish(&mut second), 16);
+        assert_eq!(second.current, 0.0);
+    }
+
+    #[test]
+    fn test_keyframes_ignore_stale_pooled_config() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            1.0,
+            AnimationConfig::new(AnimationMode::Tween(Tween::default()))
+                .with_loop(LoopMode::Infinite)
+                .with_delay(Duration::from_secs(1)),
+        );
+        motion.animate_keyframes(keyframe_track());
+        assert_eq!(frames_to_finish(&mut motion), 8);
+        assert_eq!(motion.current, 100.0);
+    }
+
+    #[test]
+    fn test_keyframes_loop_times_restarts_from_first_keyframe() {
+        let values = keyframe_values(keyframe_track().with_loop(LoopMode::Times(3)), 30);
+        assert_eq!(values[7], 100.0);
+        assert!((values[8] - 12.5).abs() < 0.01);
+        assert_eq!(values[23], 100.0);
+        assert!(values[24..].iter().all(|&value| value == 100.0));
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_keyframes(keyframe_track().with_loop(LoopMode::Times(3)));
+        assert_eq!(frames_to_finish(&mut motion), 24);
+    }
+
+    #[test]
+    fn test_keyframes_loop_infinite_never_finishes() {
+        let values = keyframe_values(keyframe_track().with_loop(LoopMode::Infinite), 200);
+        let restarts = values.windows(2).filter(|w| w[1] < w[0]).count();
+        assert_eq!(restarts, 24);
+        assert!((values[199] - 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_keyframes_loop_alternate_plays_back_and_forth() {
+        let values = keyframe_values(keyframe_track().with_loop(LoopMode::Alternate), 40);
+        assert_eq!(values[7], 100.0);
+        assert!((values[8] - 87.5).abs() < 0.01);
+        assert_eq!(values[15], 0.0);
+        assert!((values[16] - 12.5).abs() < 0.01);
+        assert_eq!(values[31], 0.0);
+    }
+
+    #[test]
+    fn test_keyframes_loop_alternate_times_ends_at_start() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_keyframes(keyframe_track().with_loop(LoopMode::AlternateTimes(2)));
+        assert_eq!(frames_to_finish(&mut motion), 32);
+        assert_eq!(motion.current, 0.0);
+    }
+
+    #[test]
+    fn test_keyframes_delay_and_direction() {
+        let delayed = keyframe_values(
+            keyframe_track().with_delay(Duration::from_secs_f32(4.0 * FRAME)),
+            12,
+        );
+        assert!(delayed[..4].iter().all(|&value| value == 0.0));
+        assert!((delayed[4] - 12.5).abs() < 0.01);
+        assert_eq!(delayed[11], 100.0);
+
+        let reversed = keyframe_values(
+            keyframe_track().with_direction(KeyframeDirection::Reverse),
+            8,
+        );
+        assert!((reversed[0] - 87.5).abs() < 0.01);
+        assert_eq!(reversed[7], 0.0);
+
+        // Alternating from the end comes back to the end
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_keyframes(
+            keyframe_track()
+                .with_direction(KeyframeDirection::Reverse)
+                .with_loop(LoopMode::AlternateTimes(1)),
+        );
+        motion.update(8.0 * FRAME);
+        assert_eq!(motion.current, 0.0);
+        frames_to_finish(&mut motion);
+        assert_eq!(motion.current, 100.0);
+
+        // Seeking counts the delay as part of the timeline
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_keyframes(keyframe_track().with_delay(Duration::from_millis(500)));
+        assert!(motion.seek_time(Duration::from_micros(562_500)));
+        assert!((motion.current - 50.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_keyframes_on_complete_runs_once_after_last_loop() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_keyframes(
+            keyframe_track()
+                .with_loop(LoopMode::Times(2))
+                .with_on_complete(move || {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                }),
+        );
+        for _ in 0..15 {
+            motion.update(FRAME);
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        frames_to_finish(&mut motion);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_keyframe_segments_with_spring_then_tween() {
+        let spring = Spring::default();
+        let fade = Tween::new(Duration::from_millis(200));
+        let track = KeyframeAnimation::starting_at(0.0f32)
+            .then(100.0, AnimationMode::Spring(spring))
+            .then(0.0, AnimationMode::Tween(fade.clone()));
+
+        let settle = spring.settling_time(-100.0, 0.0, f32::epsilon());
+        assert_eq!(track.duration, settle + fade.duration);
+        let split = track.keyframes[1].offset;
+        assert!((split - settle.as_secs_f32() / track.duration.as_secs_f32()).abs() < 1e-6);
+
+        // The spring segment follows the spring itself, overshoot included
+        let t = 0.3 * settle.as_secs_f32();
+        let (displacement, _) = spring.response(t).apply(-100.0f32, 0.0);
+        let sampled = track.sample(t / track.duration.as_secs_f32()).unwrap();
+        assert!((sampled - (100.0 + displacement)).abs() < 1e-2);
+        assert!(track.keyframes.iter().all(|k| k.offset <= 1.0));
+        assert_eq!(track.sample(split), Some(100.0));
+
+        // The tween segment runs linearly over its own duration
+        let halfway = split + (1.0 - split) / 2.0;
+        assert!((track.sample(halfway).unwrap() - 50.0).abs() < 1e-2);
+
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_keyframes(track);
+        let mut peak = 0.0f32;
+        while motion.is_running() {
+            motion.update(FRAME);
+            peak = peak.max(motion.current);
+        }
+        assert!(peak > 100.0);
+        assert_eq!(motion.current, 0.0);
+    }
+
+    #[test]
+    fn test_keyframe_decay_segment_glides_into_value() {
+        let track = KeyframeAnimation::starting_at(0.0f32)
+            .then(50.0, AnimationMode::Decay(Decay::default()));
+        assert!(track.duration > Duration::ZERO);
+
+        // Fast start, slow finish
+        let early = track.sample(0.1).unwrap();
+        let late = track.sample(0.9).unwrap() - track.sample(0.8).unwrap();
+        assert!(early > 5.0 && late < early);
+        assert_eq!(track.sample(1.0), Some(50.0));
+
+        // Zero-length segments jump straight to their value
+        let jump = KeyframeAnimation::starting_at(1.0f32)
+            .then(1.0, AnimationMode::Spring(Spring::default()));
+        assert_eq!(jump.duration, Duration::ZERO);
+        assert_eq!(jump.sample(0.5), Some(1.0));
+    }
+
+    #[test]
+    fn test_tween_with_steps_easing_jumps() {
+        use crate::animations::easing::{Easing, StepPosition};
+
+        let tween =
+            Tween::new(Duration::from_millis(125)).with_curve(Easing::steps(4, StepPosition::End));
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, AnimationConfig::new(AnimationMode::Tween(tween)));
+
+        let mut values = vec![];
+        while motion.is_running() {
+            motion.update(FRAME);
+            values.push(motion.current);
+        }
+        values.dedup();
+        assert_eq!(values, [0.0, 25.0, 50.0, 75.0, 100.0]);
+    }
+
+    fn decay_config() -> AnimationConfig {
+        AnimationConfig::new(AnimationMode::Decay(Decay::default()))
+    }
+
+    #[test]
+    fn test_decay_glides_to_computed_resting_point() {
+        let mut motion = Motion::new(100.0f32);
+        motion.animate_decay(-1000.0, decay_config());
+        let rest = 100.0 + Decay::default().resting_offset(-1000.0f32);
+        assert_eq!(motion.target, rest);
+
+        let mut previous_speed = f32::MAX;
+        while motion.is_running() {
+            motion.update(FRAME);
+            assert!(motion.current <= 100.0 && motion.current >= rest);
+            assert!(motion.velocity.abs() <= previous_speed);
+            previous_speed = motion.velocity.abs();
+        }
+        assert_eq!(motion.current, rest);
+        assert_eq!(motion.velocity, 0.0);
+    }
+
+    #[test]
+    fn test_decay_velocity_field_points_at_target() {
+        let decay = Decay::default().with_velocity(400.0);
+        let mut motion = Motion::new(0.0f32);
+        // The target only gives the direction; the decay picks its own resting point
+        motion.animate_to(-1.0, AnimationConfig::new(AnimationMode::Decay(decay)));
+        assert_eq!(motion.velocity, -400.0);
+        assert!((motion.target + 200.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_decay_within_bounds_hands_off_to_spring() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_decay_within(2000.0, -50.0, 100.0, decay_config());
+
+        let mut overshoot = 0.0f32;
+        let mut frames = 0;
+        while motion.is_running() && frames < 1000 {
+            motion.update(FRAME);
+            overshoot = overshoot.max(motion.current);
+            frames += 1;
+        }
+        assert!(overshoot > 100.0, "carries momentum past the bound");
+        assert!(overshoot < 1000.0, "the spring stops the fling");
+        assert_eq!(motion.current, 100.0);
+        assert!(!motion.is_running());
+    }
+
+    #[test]
+    fn test_decay_within_bounds_stops_without_touching_them() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_decay_within(100.0, -500.0, 500.0, decay_config());
+        frames_to_finish(&mut motion);
+        assert_eq!(motion.current, Decay::default().resting_offset(100.0f32));
+    }
+
+    #[test]
+    fn test_decay_bounds_apply_per_component() {
+        use crate::animations::transform::Transform;
+
+        let mut motion = Motion::new(Transform::identity());
+        let min = Transform::new(-1000.0, 0.0, 0.0, -10.0);
+        let max = Transform::new(1000.0, 20.0, 10.0, 10.0);
+        motion.animate_decay_within(
+            Transform::new(200.0, 400.0, 0.0, 0.0),
+            min,
+            max,
+            decay_config(),
+        );
+        let mut frames = 0;
+        while motion.is_running() && frames < 1000 {
+            motion.update(FRAME);
+            frames += 1;
+        }
+        assert!((motion.current.y - 20.0).abs() < 0.01);
+        let rest_x = Decay::default().resting_offset(200.0f32);
+        assert!((motion.current.x - rest_x).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_interrupting_decay_inherits_velocity() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_decay(1000.0, decay_config());
+        motion.update(FRAME);
+        let velocity = motion.velocity;
+
+        motion.animate_to(
+            0.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+        assert_eq!(motion.velocity, velocity);
+
+        // A new fling picks up the momentum of the old one
+        motion.animate_to(0.0, decay_config());
+        assert!(motion.target > motion.current);
+    }
+
     #[test]
     fn test_motion_backward_compatibility() {
         crate::pool::global::clear_pool();
@@ -0,0 +1,402 @@
+//! Shared frame scheduler for all motions in an app
+//!
+//! Instead of every `use_motion` spawning its own polling loop, motions register a ticker
+//! with one app-wide [`FrameScheduler`]. Only active tickers are updated, all of them in a
+//! single batched frame, and the scheduler sleeps without any timers while nothing animates.
+
+use crate::Duration;
+use crate::animations::platform::MotionClock;
+use dioxus::dioxus_core::{provide_root_context, spawn_forever, try_consume_context, use_hook};
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap};
+use std::future::poll_fn;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::task::{Poll, Waker};
+
+/// Per-frame update callback; returns `false` once it has nothing left to animate
+type Ticker = Rc<RefCell<dyn FnMut(f32) -> bool>>;
+
+/// Activation requests shared with [`SchedulerLink`]s
+///
+/// Kept behind a mutex so links can live inside `Send` motions.
+#[derive(Default)]
+struct WakeState {
+    pending: Vec<usize>,
+    waker: Option<Waker>,
+}
+
+/// Handle connecting a ticker slot to its scheduler
+///
+/// Motions keep a link and call [`SchedulerLink::wake`] whenever an animation starts,
+/// so the scheduler resumes ticking them.
+#[derive(Clone)]
+pub struct SchedulerLink {
+    id: usize,
+    wake: Arc<Mutex<WakeState>>,
+}
+
+impl SchedulerLink {
+    /// Gets the slot ID of this link
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Marks the slot as active and wakes the scheduler loop if it is idle
+    pub fn wake(&self) {
+        let waker = self.wake.lock().ok().and_then(|mut state| {
+            state.pending.push(self.id);
+            state.waker.take()
+        });
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+struct SchedulerInner {
+    tickers: HashMap<usize, Ticker>,
+    active: BTreeSet<usize>,
+    next_id: usize,
+    started: bool,
+    time_scale: f32,
+}
+
+/// App-wide frame scheduler that batches updates of every active motion
+///
+/// Provided through Dioxus context; [`use_frame_scheduler`] creates one at the root on
+/// first use. Cloning is cheap and yields a handle to the same scheduler.
+#[derive(Clone)]
+pub struct FrameScheduler {
+    inner: Rc<RefCell<SchedulerInner>>,
+    wake: Arc<Mutex<WakeState>>,
+}
+
+impl FrameScheduler {
+    /// Creates a new scheduler with no registered tickers
+    pub fn new() -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(SchedulerInner {
+                tickers: HashMap::new(),
+                active: BTreeSet::new(),
+                next_id: 0,
+                started: false,
+                time_scale: 1.0,
+            })),
+            wake: Arc::new(Mutex::new(WakeState::default())),
+        }
+    }
+
+    /// Reserves a slot whose ticker is attached later with [`FrameScheduler::attach`]
+    ///
+    /// Useful when the ticker itself needs to own something holding the link.
+    pub fn reserve(&self) -> SchedulerLink {
+        let mut inner = self.inner.borrow_mut();
+        let id = inner.next_id;
+        inner.next_id += 1;
+        SchedulerLink {
+            id,
+            wake: self.wake.clone(),
+        }
+    }
+
+    /// Attaches a ticker to a reserved slot
+    ///
+    /// The ticker is called once per frame with the frame delta in seconds while the slot is
+    /// active, and is deactivated as soon as it returns `false`.
+    pub fn attach(&self, link: &SchedulerLink, ticker: impl FnMut(f32) -> bool + 'static) {
+        let ticker: Ticker = Rc::new(RefCell::new(ticker));
+        self.inner.borrow_mut().tickers.insert(link.id, ticker);
+    }
+
+    /// Registers a ticker in a new slot and returns its link
+    pub fn register(&self, ticker: impl FnMut(f32) -> bool + 'static) -> SchedulerLink {
+        let link = self.reserve();
+        self.attach(&link, ticker);
+        link
+    }
+
+    /// Removes a slot and its ticker
+    pub fn unregister(&self, link: &SchedulerLink) {
+        let mut inner = self.inner.borrow_mut();
+        inner.tickers.remove(&link.id);
+        inner.active.remove(&link.id);
+    }
+
+    /// Gets the number of slots that will be ticked on the next frame
+    pub fn active_count(&self) -> usize {
+        self.drain_activations();
+        self.inner.borrow().active.len()
+    }
+
+    /// Gets the number of registered tickers
+    pub fn registered_count(&self) -> usize {
+        self.inner.borrow().tickers.len()
+    }
+
+    /// Sets the time scale applied to every frame delta, e.g. `0.1` for slow motion
+    ///
+    /// Affects all motions driven by this scheduler; negative values are treated as zero.
+    pub fn set_time_scale(&self, scale: f32) {
+        self.inner.borrow_mut().time_scale = scale.max(0.0);
+    }
+
+    /// Gets the time scale applied to every frame delta
+    pub fn time_scale(&self) -> f32 {
+        self.inner.borrow().time_scale
+    }
+
+    /// Runs one batched frame, ticking every active slot with `dt` seconds
+    ///
+    /// `dt` is multiplied by the [time scale](FrameScheduler::set_time_scale). Called by the
+    /// scheduler loop; exposed so custom drivers and tests can step frames.
+    pub fn tick(&self, dt: f32) {
+        self.drain_activations();
+        let dt = dt * self.time_scale();
+
+        // Collect first so tickers may start other motions without re-borrowing the scheduler
+        let tickers: Vec<(usize, Ticker)> = {
+            let inner = self.inner.borrow();
+            inner
+                .active
+                .iter()
+                .filter_map(|id| inner.tickers.get(id).map(|ticker| (*id, ticker.clone())))
+                .collect()
+        };
+
+        let finished: Vec<usize> = tickers
+            .into_iter()
+            .filter_map(|(id, ticker)| {
+                let keep_running = (ticker.borrow_mut())(dt);
+                (!keep_running).then_some(id)
+            })
+            .collect();
+
+        let mut inner = self.inner.borrow_mut();
+        for id in finished {
+            inner.active.remove(&id);
+        }
+    }
+
+    /// Spawns the scheduler loop on the root scope if it is not running yet
+    pub fn ensure_started(&self, clock: MotionClock) {
+        if self.inner.borrow().started {
+            return;
+        }
+        spawn_forever(self.clone().run(clock));
+        self.inner.borrow_mut().started = true;
+    }
+
+    /// Moves pending activations into the active set
+    fn drain_activations(&self) {
+        let pending = self
+            .wake
+            .lock()
+            .map(|mut state| std::mem::take(&mut state.pending))
+            .unwrap_or_default();
+        if pending.is_empty() {
+            return;
+        }
+
+        let mut inner = self.inner.borrow_mut();
+        for id in pending {
+            if inner.tickers.contains_key(&id) {
+                inner.active.insert(id);
+            }
+        }
+    }
+
+    /// Resolves once at least one slot is active or has requested activation
+    async fn wait_for_activation(&self) {
+        poll_fn(|cx| {
+            if !self.inner.borrow().active.is_empty() {
+                return Poll::Ready(());
+            }
+            match self.wake.lock() {
+                Ok(mut state) if state.pending.is_empty() => {
+                    state.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+                _ => Poll::Ready(()),
+            }
+        })
+        .await;
+    }
+
+    /// The single animation loop driving every registered ticker
+    async fn run(self, clock: MotionClock) {
+        let mut last_frame = clock.now();
+        let mut running_frames = 0u32;
+
+        loop {
+            if self.active_count() == 0 {
+                // Nothing animates: sleep without timers until a motion starts
+                running_frames = 0;
+                self.wait_for_activation().await;
+                last_frame = clock.now();
+            }
+
+            let now = clock.now();
+            let dt = (now.duration_since(last_frame).as_secs_f32()).min(0.1);
+            last_frame = now;
+            running_frames += 1;
+
+            self.tick(dt);
+
+            clock.delay(calculate_delay(dt, running_frames)).await;
+        }
+    }
+}
+
+impl Default for FrameScheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Gets the app-wide [`FrameScheduler`], creating it at the root scope on first use
+///
+/// The scheduler loop reads time from the [`MotionClock`] in context when it starts,
+/// so provide any custom clock before the first motion is created.
+pub fn use_frame_scheduler() -> FrameScheduler {
+    use_hook(|| {
+        let scheduler = try_consume_context::<FrameScheduler>()
+            .unwrap_or_else(|| provide_root_context(FrameScheduler::new()));
+        scheduler.ensure_started(try_consume_context::<MotionClock>().unwrap_or_default());
+        scheduler
+    })
+}
+
+/// Helper function to calculate the appropriate delay for the animation loop
+fn calculate_delay(dt: f32, running_frames: u32) -> Duration {
+    #[cfg(feature = "web")]
+    {
+        // running_frames is not used in web builds but kept for API consistency
+        let _ = running_frames;
+        match dt {
+            x if x < 0.008 => Duration::from_millis(8),  // ~120fps
+            x if x < 0.016 => Duration::from_millis(16), // ~60fps
+            _ => Duration::from_millis(32),              // ~30fps
+        }
+    }
+    #[cfg(not(feature = "web"))]
+    {
+        if running_frames <= 200 {
+            Duration::from_micros(8333) // ~120fps
+        } else {
+            match dt {
+                x if x < 0.005 => Duration::from_millis(8),  // ~120fps
+                x if x < 0.011 => Duration::from_millis(16), // ~60fps
+                _ => Duration::from_millis(33),              // ~30fps
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_scheduler_ticks_only_active_slots() {
+        let scheduler = FrameScheduler::new();
+        let ticks = Rc::new(Cell::new(0));
+
+        let counter = ticks.clone();
+        let link = scheduler.register(move |_| {
+            counter.set(counter.get() + 1);
+            true
+        });
+
+        // Registered but never woken
+        scheduler.tick(0.016);
+        assert_eq!(ticks.get(), 0);
+        assert_eq!(scheduler.active_count(), 0);
+
+        link.wake();
+        assert_eq!(scheduler.active_count(), 1);
+        scheduler.tick(0.016);
+        scheduler.tick(0.016);
+        assert_eq!(ticks.get(), 2);
+    }
+
+    #[test]
+    fn test_scheduler_deactivates_finished_tickers() {
+        let scheduler = FrameScheduler::new();
+        let remaining = Rc::new(Cell::new(3));
+
+        let frames = remaining.clone();
+        let link = scheduler.register(move |_| {
+            frames.set(frames.get() - 1);
+            frames.get() > 0
+        });
+        link.wake();
+
+        for _ in 0..5 {
+            scheduler.tick(0.016);
+        }
+        assert_eq!(remaining.get(), 0);
+        assert_eq!(scheduler.active_count(), 0);
+
+        // Waking again reactivates the same slot
+        remaining.set(1);
+        link.wake();
+        scheduler.tick(0.016);
+        assert_eq!(remaining.get(), 0);
+    }
+
+    #[test]
+    fn test_scheduler_batches_many_tickers() {
+        let scheduler = FrameScheduler::new();
+        let ticks = Rc::new(Cell::new(0));
+
+        let links: Vec<_> = (0..300)
+            .map(|_| {
+                let counter = ticks.clone();
+                scheduler.register(move |_| {
+                    counter.set(counter.get() + 1);
+                    true
+                })
+            })
+            .collect();
+        links.iter().for_each(SchedulerLink::wake);
+
+        scheduler.tick(0.016);
+        assert_eq!(ticks.get(), 300);
+
+        scheduler.unregister(&links[0]);
+        assert_eq!(scheduler.registered_count(), 299);
+        assert_eq!(scheduler.active_count(), 299);
+    }
+
+    #[test]
+    fn test_scheduler_ignores_wake_for_unregistered_slot() {
+        let scheduler = FrameScheduler::new();
+        let link = scheduler.register(|_| true);
+        scheduler.unregister(&link);
+
+        link.wake();
+        assert_eq!(scheduler.active_count(), 0);
+    }
+
+    #[test]
+    fn test_scheduler_time_scale_scales_frame_delta() {
+        let scheduler = FrameScheduler::new();
+        let received = Rc::new(Cell::new(0.0));
+
+        let delta = received.clone();
+        let link = scheduler.register(move |dt| {
+            delta.set(dt);
+            true
+        });
+        link.wake();
+
+        scheduler.set_time_scale(0.1);
+        scheduler.tick(0.016);
+        assert!((received.get() - 0.0016).abs() < 1e-6);
+
+        scheduler.set_time_scale(-1.0);
+        assert_eq!(scheduler.time_scale(), 0.0);
+    }
+}
@@ -1,17 +1,82 @@
 //! `AnimationSequence<T>` - Optimized animation step sequences
 
-use crate::animations::core::Animatable;
+use crate::Duration;
+use crate::animations::core::{Animatable, AnimationMode, LoopMode, OnComplete};
+use crate::animations::tween::Tween;
 use crate::prelude::AnimationConfig;
 
 use std::sync::Arc;
 use std::sync::Mutex;
-use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+
+/// Callback that receives the index of a sequence step
+pub type StepCallback = Arc<Mutex<dyn FnMut(usize) + Send + 'static>>;
+
+/// What a sequence step does
+#[derive(Clone, Default)]
+pub enum StepKind {
+    /// Animates to the step's target
+    #[default]
+    Animate,
+    /// Holds the current value for the length of the step's tween
+    Wait,
+    /// Runs a callback and moves straight on to the next step
+    Call(OnComplete),
+}
 
+/// One step of an [`AnimationSequence`]
+///
+/// Built with [`AnimationStep::new`], [`AnimationStep::wait`] or [`AnimationStep::call`].
 #[derive(Clone)]
+#[non_exhaustive]
 pub struct AnimationStep<T: Animatable> {
+    /// Value the step animates to; unused by steps that don't animate
     pub target: T,
     pub config: Arc<AnimationConfig>,
     pub predicted_next: Option<T>,
+    pub kind: StepKind,
+}
+
+impl<T: Animatable> AnimationStep<T> {
+    /// Creates a step that animates to `target`
+    pub fn new(target: T, config: AnimationConfig) -> Self {
+        Self {
+            target,
+            config: Arc::new(config),
+            predicted_next: None,
+            kind: StepKind::Animate,
+        }
+    }
+
+    /// Creates a step that holds the current value for `duration`
+    pub fn wait(duration: Duration) -> Self {
+        Self {
+            target: T::zero(),
+            config: Arc::new(AnimationConfig::new(AnimationMode::Tween(Tween::new(
+                duration,
+            )))),
+            predicted_next: None,
+            kind: StepKind::Wait,
+        }
+    }
+
+    /// Creates a step that runs `f` without animating
+    pub fn call<F: FnMut() + Send + 'static>(f: F) -> Self {
+        Self {
+            kind: StepKind::Call(Arc::new(Mutex::new(f))),
+            ..Self::wait(Duration::ZERO)
+        }
+    }
+
+    /// Returns `true` if the step animates towards its target
+    pub fn animates(&self) -> bool {
+        matches!(self.kind, StepKind::Animate)
+    }
+
+    /// Gets the value the step ends at when it starts from `from`
+    pub fn target_from(&self, from: T) -> T {
+        if self.animates() { self.target } else { from }
+    }
 }
 
 /// Optimized animation sequence that uses shared immutable data and atomic counters
@@ -20,25 +85,30 @@ pub struct AnimationSequence<T: Animatable> {
     /// Immutable shared steps - no cloning needed
     steps: Arc<[AnimationStep<T>]>,
     /// Atomic counter for current step - thread-safe without locks
-    current_step: AtomicU8,
-    /// Completion callback - thread-safe with Mutex to allow execution without ownership
-    #[allow(clippy::type_complexity)]
-    on_complete: Arc<Mutex<Option<Box<dyn FnOnce() + Send>>>>,
+    current_step: AtomicUsize,
+    /// How the whole sequence repeats once its last step completes
+    loop_mode: LoopMode,
+    /// Whether the steps currently play from last to first
+    reversed: AtomicBool,
+    /// Passes through the steps completed so far, in either direction
+    completed_loops: AtomicU64,
+    /// Completion callback, shared with every clone of the sequence
+    on_complete: Option<OnComplete>,
+    /// Runs with the index of each step as it starts
+    on_step_start: Option<StepCallback>,
+    /// Runs with the index of each step as it completes
+    on_step_complete: Option<StepCallback>,
 }
 
 impl<T: Animatable> AnimationSequence<T> {
     /// Creates a new empty animation sequence
     pub fn new() -> Self {
-        Self {
-            steps: Arc::new([]),
-            current_step: AtomicU8::new(0),
-            on_complete: Arc::new(Mutex::new(None)),
-        }
+        Self::from_steps(Vec::new())
     }
 
     /// Creates a new animation sequence with specified capacity hint
     /// Note: This is kept for API compatibility but doesn't pre-allocate since we use Arc<\[T\]>
-    pub fn with_capacity(_capacity: u8) -> Self {
+    pub fn with_capacity(_capacity: usize) -> Self {
         Self::new()
     }
 
@@ -46,71 +116,126 @@ impl<T: Animatable> AnimationSequence<T> {
     pub fn from_steps(steps: Vec<AnimationStep<T>>) -> Self {
         Self {
             steps: steps.into(),
-            current_step: AtomicU8::new(0),
-            on_complete: Arc::new(Mutex::new(None)),
+            current_step: AtomicUsize::new(0),
+            loop_mode: LoopMode::None,
+            reversed: AtomicBool::new(false),
+            completed_loops: AtomicU64::new(0),
+            on_complete: None,
+            on_step_start: None,
+            on_step_complete: None,
         }
     }
 
     /// Creates a new animation sequence with a completion callback
     pub fn with_on_complete<F>(steps: Vec<AnimationStep<T>>, on_complete: F) -> Self
     where
-        F: FnOnce() + Send + 'static,
+        F: FnMut() + Send + 'static,
     {
-        Self {
-            steps: steps.into(),
-            current_step: AtomicU8::new(0),
-            on_complete: Arc::new(Mutex::new(Some(Box::new(on_complete)))),
-        }
+        Self::from_steps(steps).on_complete(on_complete)
     }
 
     /// Reserve additional capacity (kept for API compatibility, but no-op since we use Arc<\[T\]>)
-    pub fn reserve(&mut self, _additional: u8) {
+    pub fn reserve(&mut self, _additional: usize) {
         // No-op for Arc<[T]> - kept for backward compatibility
     }
 
     /// Adds a new step to the sequence and returns a new sequence
     /// This creates a new Arc with all steps to maintain immutability
     pub fn then(self, target: T, config: AnimationConfig) -> Self {
-        let predicted_next = if self.steps.is_empty() {
-            None
-        } else {
-            self.steps
-                .last()
-                .map(|last_step| last_step.target.interpolate(&target, 0.5))
-        };
-
-        let new_step = AnimationStep {
-            target,
-            config: Arc::new(config),
+        let predicted_next = self
+            .steps
+            .iter()
+            .rev()
+            .find(|step| step.animates())
+            .map(|last_step| last_step.target.interpolate(&target, 0.5));
+
+        self.push(AnimationStep {
             predicted_next,
-        };
+            ..AnimationStep::new(target, config)
+        })
+    }
+
+    /// Adds a step that holds the current value for `duration`
+    pub fn wait(self, duration: Duration) -> Self {
+        self.push(AnimationStep::wait(duration))
+    }
 
+    /// Adds a step that runs `f` once the previous steps are done, then moves straight on
+    pub fn call<F: FnMut() + Send + 'static>(self, f: F) -> Self {
+        self.push(AnimationStep::call(f))
+    }
+
+    fn push(self, step: AnimationStep<T>) -> Self {
         // Create new vector with existing steps plus the new one
         let mut new_steps: Vec<AnimationStep<T>> = self.steps.iter().cloned().collect();
-        new_steps.push(new_step);
+        new_steps.push(step);
 
         Self {
             steps: new_steps.into(),
-            current_step: AtomicU8::new(self.current_step.load(Ordering::Relaxed)),
-            on_complete: self.on_complete,
+            ..self
         }
     }
 
+    /// Repeats the whole sequence
+    ///
+    /// Repeating loops jump back to the value the sequence started from. Alternating loops
+    /// play the steps in reverse order on every other pass, each step heading back to the
+    /// value it started from, and the completion callback runs once the last pass is done.
+    pub fn with_loop(mut self, loop_mode: LoopMode) -> Self {
+        self.loop_mode = loop_mode;
+        self
+    }
+
+    /// Gets how the whole sequence repeats
+    pub fn loop_mode(&self) -> LoopMode {
+        self.loop_mode
+    }
+
+    /// Returns `true` if the sequence or any of its steps loops
+    pub fn is_looping(&self) -> bool {
+        self.loop_mode != LoopMode::None
+            || self.steps.iter().any(|step| {
+                step.config
+                    .loop_mode
+                    .is_some_and(|loop_mode| loop_mode != LoopMode::None)
+            })
+    }
+
+    /// Returns `true` while the steps play from last to first
+    pub fn is_reversed(&self) -> bool {
+        self.reversed.load(Ordering::Relaxed)
+    }
+
     /// Sets a completion callback
-    pub fn on_complete<F: FnOnce() + Send + 'static>(self, f: F) -> Self {
-        if let Ok(mut guard) = self.on_complete.lock() {
-            *guard = Some(Box::new(f));
-        }
+    pub fn on_complete<F: FnMut() + Send + 'static>(mut self, f: F) -> Self {
+        self.on_complete = Some(Arc::new(Mutex::new(f)));
+        self
+    }
+
+    /// Sets a callback that runs with the index of each step as it starts
+    pub fn on_step_start<F: FnMut(usize) + Send + 'static>(mut self, f: F) -> Self {
+        self.on_step_start = Some(Arc::new(Mutex::new(f)));
+        self
+    }
+
+    /// Sets a callback that runs with the index of each step as it completes
+    pub fn on_step_complete<F: FnMut(usize) + Send + 'static>(mut self, f: F) -> Self {
+        self.on_step_complete = Some(Arc::new(Mutex::new(f)));
         self
     }
 
-    /// Advances to the next step in the sequence
+    /// Advances to the next step in the direction of play
     /// Returns true if advanced, false if already at the end
     pub fn advance_step(&self) -> bool {
         let current = self.current_step.load(Ordering::Relaxed);
-        let total_steps = self.steps.len() as u8;
 
-        if current < total_steps.saturating_sub(1) {
+        if self.is_reversed() {
+            if current > 0 {
+                self.current_step.store(current - 1, Ordering::Relaxed);
+                return true;
+            }
+            false
+        } else if current < self.steps.len().saturating_sub(1) {
             self.current_step.store(current + 1, Ordering::Relaxed);
             true
         } else {
@@ -119,30 +244,30 @@ impl<T: Animatable> AnimationSequence<T> {
     }
 
     /// Gets the current step index
-    pub fn current_step_index(&self) -> u8 {
+    pub fn current_step_index(&self) -> usize {
         self.current_step.load(Ordering::Relaxed)
     }
 
     /// Gets the current step index (kept for backward compatibility)
-    pub fn current_step(&self) -> u8 {
+    pub fn current_step(&self) -> usize {
         self.current_step_index()
     }
 
     /// Gets the configuration for the current step
     pub fn current_config(&self) -> Option<&AnimationConfig> {
-        let current = self.current_step.load(Ordering::Relaxed) as usize;
+        let current = self.current_step.load(Ordering::Relaxed);
         self.steps.get(current).map(|step| step.config.as_ref())
     }
 
     /// Gets the target value for the current step
     pub fn current_target(&self) -> Option<T> {
-        let current = self.current_step.load(Ordering::Relaxed) as usize;
+        let current = self.current_step.load(Ordering::Relaxed);
         self.steps.get(current).map(|step| step.target)
     }
 
     /// Gets the current step data
     pub fn current_step_data(&self) -> Option<&AnimationStep<T>> {
-        let current = self.current_step.load(Ordering::Relaxed) as usize;
+        let current = self.current_step.load(Ordering::Relaxed);
         self.steps.get(current)
     }
 
@@ -151,11 +276,14 @@ impl<T: Animatable> AnimationSequence<T> {
         &self.steps
     }
 
-    /// Checks if the sequence is complete (at the last step)
+    /// Checks if the sequence is complete (at the last step in the direction of play)
     pub fn is_complete(&self) -> bool {
         let current = self.current_step.load(Ordering::Relaxed);
-        let total_steps = self.steps.len() as u8;
-        current >= total_steps.saturating_sub(1)
+        if self.is_reversed() {
+            current == 0
+        } else {
+            current >= self.steps.len().saturating_sub(1)
+        }
     }
 
     /// Gets the total number of steps
@@ -163,28 +291,151 @@ impl<T: Animatable> AnimationSequence<T> {
         self.steps.len()
     }
 
-    /// Resets the sequence to the first step
+    /// Resets the sequence to the first step, playing forwards with no loops completed
     pub fn reset(&self) {
         self.current_step.store(0, Ordering::Relaxed);
+        self.reversed.store(false, Ordering::Relaxed);
+        self.completed_loops.store(0, Ordering::Relaxed);
+    }
+
+    /// Jumps directly to the step at `index`, clamped to the last step
+    pub fn jump_to_step(&self, index: usize) {
+        let last = self.steps.len().saturating_sub(1);
+        self.current_step.store(index.min(last), Ordering::Relaxed);
     }
 
     /// Executes the completion callback if present
     /// This method is thread-safe and can be called without ownership
     pub fn execute_completion(&self) {
-        if let Ok(mut guard) = self.on_complete.lock() {
-            if let Some(callback) = guard.take() {
+        if let Some(callback) = &self.on_complete
+            && let Ok(mut callback) = callback.lock()
+        {
+            callback();
+        }
+    }
+
+    /// Starts the current step and returns it once it is one that takes time
+    ///
+    /// Call steps run here and are skipped over. Returns `None` if the sequence ran out of
+    /// steps.
+    pub(crate) fn begin_step(&self) -> Option<&AnimationStep<T>> {
+        loop {
+            let index = self.current_step_index();
+            let step = self.steps.get(index)?;
+            Self::notify(&self.on_step_start, index);
+
+            let StepKind::Call(callback) = &step.kind else {
+                return Some(step);
+            };
+            if let Ok(mut callback) = callback.lock() {
                 callback();
             }
+            Self::notify(&self.on_step_complete, index);
+            if !self.advance_step() {
+                return None;
+            }
+        }
+    }
+
+    /// Marks the current step as completed
+    pub(crate) fn complete_step(&self) {
+        Self::notify(&self.on_step_complete, self.current_step_index());
+    }
+
+    /// Sets the direction of play, leaving the current step as it is
+    pub(crate) fn set_reversed(&self, reversed: bool) {
+        self.reversed.store(reversed, Ordering::Relaxed);
+    }
+
+    /// Starts the next pass through the steps if the loop mode asks for one
+    ///
+    /// Returns `false` once every pass is done. Sequences made only of call steps never
+    /// loop, since a pass through them takes no time.
+    pub(crate) fn begin_loop(&self) -> bool {
+        let (passes, alternate) = match self.loop_mode {
+            LoopMode::None => return false,
+            LoopMode::Infinite => (None, false),
+            LoopMode::Times(count) => (Some(u64::from(count)), false),
+            LoopMode::Alternate => (None, true),
+            LoopMode::AlternateTimes(count) => (Some(u64::from(count) * 2), true),
+        };
+        if self
+            .steps
+            .iter()
+            .all(|step| matches!(step.kind, StepKind::Call(_)))
+        {
+            return false;
+        }
+
+        let completed = self.completed_loops.fetch_add(1, Ordering::Relaxed) + 1;
+        if passes.is_some_and(|passes| completed >= passes) {
+            return false;
+        }
+
+        if alternate {
+            self.set_reversed(!self.is_reversed());
+        }
+        let first = if self.is_reversed() {
+            self.steps.len().saturating_sub(1)
+        } else {
+            0
+        };
+        self.current_step.store(first, Ordering::Relaxed);
+        true
+    }
+
+    /// Gets the value the step at `index` starts from when the sequence plays forwards
+    /// from `origin`
+    pub fn start_value(&self, index: usize, origin: T) -> T {
+        self.steps
+            .iter()
+            .take(index)
+            .fold(origin, |from, step| step.target_from(from))
+    }
+
+    /// Gets the value the current step heads to in the direction of play
+    ///
+    /// Steps that don't animate hold `current`; reversed steps head back to the value they
+    /// start from when the sequence plays forwards from `origin`.
+    pub(crate) fn current_step_target(&self, current: T, origin: T) -> T {
+        let index = self.current_step_index();
+        match self.steps.get(index) {
+            Some(step) if step.animates() && self.is_reversed() => self.start_value(index, origin),
+            Some(step) => step.target_from(current),
+            None => current,
+        }
+    }
+
+    fn notify(callback: &Option<StepCallback>, index: usize) {
+        if let Some(callback) = callback
+            && let Ok(mut callback) = callback.lock()
+        {
+            callback(index);
         }
     }
 }
 
+impl<T: Animatable + Send + 'static> AnimationSequence<T> {
+    /// Runs the sequence offline from `initial` and returns every sampled value
+    ///
+    /// See [`Motion::simulate`](crate::motion::Motion::simulate) for sampling details.
+    pub fn simulate(&self, initial: T, dt: f32) -> crate::motion::Trajectory<T> {
+        crate::motion::Motion::simulate_sequence(initial, self.clone(), dt)
+    }
+}
+
 impl<T: Animatable> Clone for AnimationSequence<T> {
     fn clone(&self) -> Self {
         Self {
             steps: self.steps.clone(), // Arc clone is cheap
-            current_step: AtomicU8::new(self.current_step.load(Ordering::Relaxed)),
-            on_complete: Arc::new(Mutex::new(None)), // Callbacks can't be cloned
+            current_step: AtomicUsize::new(self.current_step.load(Ordering::Relaxed)),
+            loop_mode: self.loop_mode,
+            reversed: AtomicBool::new(self.is_reversed()),
+            completed_loops: AtomicU64::new(self.completed_loops.load(Ordering::Relaxed)),
+            // Clones share the callbacks
+            on_complete: self.on_complete.clone(),
+            on_step_start: self.on_step_start.clone(),
+            on_step_complete: self.on_step_complete.clone(),
         }
     }
 }
@@ -201,32 +452,24 @@ mod tests {
     use super::*;
     use crate::animations::core::AnimationMode;
     use crate::animations::spring::Spring;
+    use crate::test_utils::EventLog;
     use std::sync::{Arc, Mutex};
 
     #[test]
     fn test_animation_sequence_basic() {
         let steps = vec![
-            AnimationStep {
-                target: 10.0f32,
-                config: Arc::new(AnimationConfig::new(AnimationMode::Spring(
-                    Spring::default(),
-                ))),
-                predicted_next: None,
-            },
-            AnimationStep {
-                target: 20.0f32,
-                config: Arc::new(AnimationConfig::new(AnimationMode::Spring(
-                    Spring::default(),
-                ))),
-                predicted_next: None,
-            },
-            AnimationStep {
-                target: 30.0f32,
-                config: Arc::new(AnimationConfig::new(AnimationMode::Spring(
-                    Spring::default(),
-                ))),
-                predicted_next: None,
-            },
+            AnimationStep::new(
+                10.0f32,
+                AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+            ),
+            AnimationStep::new(
+                20.0f32,
+                AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+            ),
+            AnimationStep::new(
+                30.0f32,
+                AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+            ),
         ];
 
         let sequence = AnimationSequence::from_steps(steps);
@@ -286,18 +529,38 @@ mod tests {
         assert!(sequence.is_complete());
     }
 
+    #[test]
+    fn test_animation_sequence_past_255_steps() {
+        let steps = (0..600)
+            .map(|i| AnimationStep::new(i as f32, AnimationConfig::default()))
+            .collect();
+        let sequence = AnimationSequence::from_steps(steps);
+
+        let mut advanced = 0;
+        while sequence.advance_step() {
+            advanced += 1;
+        }
+        assert_eq!(advanced, 599);
+        assert_eq!(sequence.current_step_index(), 599);
+        assert_eq!(sequence.current_target(), Some(599.0));
+        assert!(sequence.is_complete());
+
+        sequence.jump_to_step(256);
+        assert_eq!(sequence.current_target(), Some(256.0));
+        assert!(!sequence.is_complete());
+        sequence.jump_to_step(usize::MAX);
+        assert_eq!(sequence.current_step_index(), 599);
+    }
+
     #[test]
     fn test_animation_sequence_with_callback() {
         let callback_executed = Arc::new(Mutex::new(false));
         let callback_executed_clone = callback_executed.clone();
 
-        let steps = vec![AnimationStep {
-            target: 10.0f32,
-            config: Arc::new(AnimationConfig::new(AnimationMode::Spring(
-                Spring::default(),
-            ))),
-            predicted_next: None,
-        }];
+        let steps = vec![AnimationStep::new(
+            10.0f32,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        )];
 
         let sequence = AnimationSequence::with_on_complete(steps, move || {
             *callback_executed_clone.lock().unwrap() = true;
@@ -314,13 +577,10 @@ mod tests {
         let callback_executed = Arc::new(Mutex::new(false));
         let callback_executed_clone = callback_executed.clone();
 
-        let steps = vec![AnimationStep {
-            target: 10.0f32,
-            config: Arc::new(AnimationConfig::new(AnimationMode::Spring(
-                Spring::default(),
-            ))),
-            predicted_next: None,
-        }];
+        let steps = vec![AnimationStep::new(
+            10.0f32,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        )];
 
         let sequence = AnimationSequence::with_on_complete(steps, move || {
             *callback_executed_clone.lock().unwrap() = true;
@@ -348,13 +608,10 @@ mod tests {
 
     #[test]
     fn test_animation_sequence_clone() {
-        let steps = vec![AnimationStep {
-            target: 10.0f32,
-            config: Arc::new(AnimationConfig::new(AnimationMode::Spring(
-                Spring::default(),
-            ))),
-            predicted_next: None,
-        }];
+        let steps = vec![AnimationStep::new(
+            10.0f32,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        )];
 
         let sequence1 = AnimationSequence::from_steps(steps);
         sequence1.advance_step(); // This won't work since there's only one step, but let's test the clone
@@ -394,4 +651,76 @@ mod tests {
         let mut sequence_mut = sequence.clone();
         sequence_mut.reserve(5);
     }
+
+    #[test]
+    fn test_animation_sequence_clones_share_callbacks() {
+        let completions = Arc::new(Mutex::new(0));
+        let counter = completions.clone();
+        let sequence = AnimationSequence::new()
+            .then(10.0f32, AnimationConfig::default())
+            .on_complete(move || *counter.lock().unwrap() += 1);
+
+        let clone = sequence.clone();
+        sequence.execute_completion();
+        clone.execute_completion();
+        assert_eq!(*completions.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_animation_sequence_begin_step_runs_call_steps() {
+        let log = EventLog::default();
+        let sequence = AnimationSequence::new()
+            .call(log.recorder("call"))
+            .wait(Duration::from_millis(100))
+            .then(10.0f32, AnimationConfig::default())
+            .on_step_start(log.step("start"))
+            .on_step_complete(log.step("complete"));
+
+        // The call step runs and is skipped, the wait step takes time
+        let step = sequence.begin_step().unwrap();
+        assert_eq!(step.target_from(5.0), 5.0);
+        assert_eq!(sequence.current_step_index(), 1);
+        sequence.complete_step();
+        assert!(sequence.advance_step());
+        assert_eq!(sequence.begin_step().unwrap().target_from(5.0), 10.0);
+
+        assert_eq!(
+            log.events(),
+            [
+                "start 0",
+                "call",
+                "complete 0",
+                "start 1",
+                "complete 1",
+                "start 2"
+            ]
+            .map(String::from)
+        );
+        // `then` predicts from the last step that animates
+        assert_eq!(sequence.steps()[2].predicted_next, None);
+    }
+
+    #[test]
+    fn test_animation_sequence_reversed_traversal() {
+        let sequence = AnimationSequence::new()
+            .then(10.0f32, AnimationConfig::default())
+            .wait(Duration::from_millis(50))
+            .then(30.0, AnimationConfig::default());
+        assert_eq!(sequence.start_value(0, 5.0), 5.0);
+        assert_eq!(sequence.start_value(2, 5.0), 10.0);
+
+        sequence.jump_to_step(2);
+        sequence.set_reversed(true);
+        assert_eq!(sequence.current_step_target(30.0, 5.0), 10.0);
+        assert!(sequence.advance_step());
+        assert_eq!(sequence.current_step_target(10.0, 5.0), 10.0);
+        assert!(sequence.advance_step());
+        assert_eq!(sequence.current_step_target(10.0, 5.0), 5.0);
+        assert!(sequence.is_complete());
+        assert!(!sequence.advance_step());
+
+        sequence.reset();
+        assert!(!sequence.is_reversed());
+        assert_eq!(sequence.current_step_index(), 0);
+    }
 }
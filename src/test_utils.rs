@@ -0,0 +1,72 @@
+//! Helpers shared by the unit tests of motions and sequences
+#![allow(clippy::unwrap_used)]
+
+use crate::Duration;
+use crate::animations::core::AnimationMode;
+use crate::animations::tween::Tween;
+use crate::motion::Motion;
+use crate::prelude::AnimationConfig;
+use crate::sequence::AnimationSequence;
+use std::sync::{Arc, Mutex};
+
+/// Frame delta that divides the test durations exactly
+pub(crate) const FRAME: f32 = 1.0 / 64.0;
+
+/// Linear tween lasting `millis`; 125ms lasts exactly eight frames
+pub(crate) fn linear_tween(millis: u64) -> AnimationConfig {
+    AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(
+        millis,
+    ))))
+}
+
+/// Plays a motion until it stops, returning its value after every frame
+pub(crate) fn play(motion: &mut Motion<f32>) -> Vec<f32> {
+    let mut values = Vec::new();
+    while motion.is_running() && values.len() < 1000 {
+        motion.update(FRAME);
+        values.push(motion.current);
+    }
+    values
+}
+
+/// Steps until the motion stops, returning the number of frames taken
+pub(crate) fn frames_to_finish(motion: &mut Motion<f32>) -> usize {
+    play(motion).len()
+}
+
+/// Ordered log of events written by sequence callbacks
+#[derive(Clone, Default)]
+pub(crate) struct EventLog(Arc<Mutex<Vec<String>>>);
+
+impl EventLog {
+    /// Gets a callback that logs `event` every time it runs
+    pub(crate) fn recorder(&self, event: &'static str) -> impl FnMut() + Send + 'static {
+        let events = self.0.clone();
+        move || events.lock().unwrap().push(event.to_string())
+    }
+
+    /// Gets a step callback that logs `prefix` followed by the step index
+    pub(crate) fn step(&self, prefix: &'static str) -> impl FnMut(usize) + Send + 'static {
+        let events = self.0.clone();
+        move |index| events.lock().unwrap().push(format!("{prefix} {index}"))
+    }
+
+    /// Gets the events logged so far
+    pub(crate) fn events(&self) -> Vec<String> {
+        self.0.lock().unwrap().clone()
+    }
+
+    pub(crate) fn clear(&self) {
+        self.0.lock().unwrap().clear();
+    }
+}
+
+/// Logs the steps of `sequence` as `start 0`, `complete 0`, ... and the final `done`
+pub(crate) fn recorded(sequence: AnimationSequence<f32>) -> (AnimationSequence<f32>, EventLog) {
+    let log = EventLog::default();
+    let sequence = sequence
+        .on_step_start(log.step("start"))
+        .on_step_complete(log.step("complete"))
+        .on_complete(log.recorder("done"));
+    (sequence, log)
+}
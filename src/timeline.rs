@@ -0,0 +1,1077 @@
+//! `Timeline` - Orchestration of many motions on one shared clock
+//!
+//! A timeline places animations for any number of motions, of any [`Animatable`] types,
+//! at absolute times, relative to each other or at named labels, and plays, pauses, seeks
+//! and reverses all of them together. Positions use the GSAP timeline syntax:
+//!
+//! | Position      | Starts                                             |
+//! |---------------|----------------------------------------------------|
+//! | `"0.5s"`      | at 0.5s                                            |
+//! | `"+=200ms"`   | 200ms after the end of the timeline                |
+//! | `"-=200ms"`   | 200ms before the end of the timeline (an overlap)  |
+//! | `"<"`         | with the previously added clip                     |
+//! | `">"`         | after the previously added clip                    |
+//! | `"<+=100ms"`  | 100ms after the previously added clip starts       |
+//! | `"intro"`     | at the label `intro`                               |
+//! | `"intro+=1s"` | 1s after the label `intro`                         |
+
+use crate::Duration;
+use crate::animations::core::{Animatable, AnimationConfig, AnimationMode};
+use crate::animations::css::{CssParseError, parse_duration};
+use crate::animations::tween::Tween;
+use crate::keyframes::{KeyframeAnimation, KeyframeDirection, KeyframeTrack};
+use crate::manager::AnimationManager;
+use crate::scheduler::{SchedulerLink, use_frame_scheduler};
+use crate::sequence::{AnimationSequence, StepKind};
+
+use dioxus::dioxus_core::use_drop;
+use dioxus::prelude::{ReadableExt, Signal, WritableExt, use_hook, use_signal};
+use std::any::Any;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Errors produced while building a [`Timeline`]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum TimelineError {
+    #[error("Invalid timeline position `{position}`: {source}")]
+    InvalidPosition {
+        position: String,
+        source: CssParseError,
+    },
+    #[error("Unknown timeline label `{0}`")]
+    UnknownLabel(String),
+}
+
+impl From<Infallible> for TimelineError {
+    fn from(never: Infallible) -> Self {
+        match never {}
+    }
+}
+
+/// Point of the timeline a [`Position`] is relative to
+#[derive(Debug, Clone, PartialEq)]
+pub enum Anchor {
+    /// The start of the timeline
+    Start,
+    /// The end of the last clip on the timeline
+    End,
+    /// The start of the most recently added clip
+    PreviousStart,
+    /// The end of the most recently added clip
+    PreviousEnd,
+    /// A label added with [`Timeline::add_label`]
+    Label(String),
+}
+
+/// Where a clip or label is placed on a [`Timeline`]
+///
+/// Parsed from the strings described in the [module docs](self), or built from a
+/// [`Duration`] for an absolute time. Positions before the start of the timeline are
+/// clamped to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Position {
+    /// Point the offset is measured from
+    pub anchor: Anchor,
+    /// Distance from the anchor
+    pub offset: Duration,
+    /// Whether the offset goes back from the anchor rather than forward
+    pub before: bool,
+}
+
+impl Position {
+    /// After everything already on the timeline
+    pub fn end() -> Self {
+        Self::from(Anchor::End)
+    }
+
+    /// With the previously added clip
+    pub fn with_previous() -> Self {
+        Self::from(Anchor::PreviousStart)
+    }
+
+    /// After the previously added clip
+    pub fn after_previous() -> Self {
+        Self::from(Anchor::PreviousEnd)
+    }
+
+    /// At a label
+    pub fn label(name: impl Into<String>) -> Self {
+        Self::from(Anchor::Label(name.into()))
+    }
+
+    /// Moves the position `offset` after the anchor
+    pub fn plus(mut self, offset: Duration) -> Self {
+        self.offset = offset;
+        self.before = false;
+        self
+    }
+
+    /// Moves the position `offset` before the anchor
+    pub fn minus(mut self, offset: Duration) -> Self {
+        self.offset = offset;
+        self.before = true;
+        self
+    }
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Self::end()
+    }
+}
+
+impl From<Anchor> for Position {
+    fn from(anchor: Anchor) -> Self {
+        Self {
+            anchor,
+            offset: Duration::ZERO,
+            before: false,
+        }
+    }
+}
+
+impl From<Duration> for Position {
+    fn from(time: Duration) -> Self {
+        Self::from(Anchor::Start).plus(time)
+    }
+}
+
+impl FromStr for Position {
+    type Err = TimelineError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let position = input.trim();
+        let invalid = |source| TimelineError::InvalidPosition {
+            position: input.to_string(),
+            source,
+        };
+        // Splits `anchor+=time` / `anchor-=time` into the anchor, the offset and its direction
+        let relative = |text: &str| -> Result<(String, Duration, bool), TimelineError> {
+            match text.find("+=").or_else(|| text.find("-=")) {
+                Some(at) => {
+                    let offset = parse_duration(&text[at + 2..]).map_err(invalid)?;
+                    let before = text[at..].starts_with('-');
+                    Ok((text[..at].trim().to_string(), offset, before))
+                }
+                None => Ok((text.to_string(), Duration::ZERO, false)),
+            }
+        };
+
+        if position.is_empty() {
+            return Err(invalid(CssParseError::Empty));
+        }
+        if let Ok(time) = parse_duration(position) {
+            return Ok(Self::from(time));
+        }
+
+        let (anchor, offset, before) = relative(position)?;
+        let anchor = match anchor.as_str() {
+            "" => Anchor::End,
+            "<" => Anchor::PreviousStart,
+            ">" => Anchor::PreviousEnd,
+            label => Anchor::Label(label.to_string()),
+        };
+        Ok(Self {
+            anchor,
+            offset,
+            before,
+        })
+    }
+}
+
+impl TryFrom<&str> for Position {
+    type Error = TimelineError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        input.parse()
+    }
+}
+
+/// Handle to a motion added to a [`Timeline`] with [`Timeline::track`]
+pub struct Track<T> {
+    index: usize,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Track<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Track<T> {}
+
+/// Something a timeline track can drive
+///
+/// Implemented for every [`AnimationManager`], such as the motions returned by
+/// [`use_motion`](crate::use_motion).
+pub trait TimelineTarget<T: Animatable> {
+    /// Gets the current value
+    fn value(&self) -> T;
+    /// Starts `track` frozen in scrub mode
+    fn activate(&mut self, track: Arc<KeyframeTrack<T>>);
+    /// Shows the active animation `time` after its start
+    fn seek(&mut self, time: Duration);
+    /// Stops the animation and leaves scrub mode, keeping the current value
+    fn release(&mut self);
+}
+
+impl<T, M> TimelineTarget<T> for M
+where
+    T: Animatable + Send + 'static,
+    M: AnimationManager<T>,
+{
+    fn value(&self) -> T {
+        self.get_value()
+    }
+
+    fn activate(&mut self, track: Arc<KeyframeTrack<T>>) {
+        self.set_scrubbing(true);
+        self.animate_track(track);
+    }
+
+    fn seek(&mut self, time: Duration) {
+        self.seek_time(time);
+    }
+
+    fn release(&mut self) {
+        self.stop();
+        self.set_scrubbing(false);
+    }
+}
+
+/// Builds a clip's keyframes from the value the clip starts at
+type ClipBuilder<T> = Box<dyn Fn(T) -> KeyframeAnimation<T>>;
+
+/// One animation of a track, resolved into keyframes from the value it starts at
+struct Clip<T: Animatable> {
+    start: Duration,
+    from: T,
+    build: ClipBuilder<T>,
+    track: Arc<KeyframeTrack<T>>,
+}
+
+impl<T: Animatable> Clip<T> {
+    fn new(start: Duration, from: T, build: ClipBuilder<T>) -> Self {
+        Self {
+            start,
+            from,
+            track: Arc::new(build(from).track()),
+            build,
+        }
+    }
+
+    /// Resolves the clip again from a new starting value
+    fn rebuild(&mut self, from: T) {
+        self.from = from;
+        self.track = Arc::new((self.build)(from).track());
+    }
+
+    fn end(&self) -> Duration {
+        self.start + self.track.duration()
+    }
+
+    /// Gets the clip's value `time` after the start of the timeline
+    fn sample(&self, time: Duration) -> T {
+        let local = time.saturating_sub(self.start);
+        let progress = if self.track.duration().is_zero() {
+            1.0
+        } else {
+            local.as_secs_f32() / self.track.duration().as_secs_f32()
+        };
+        self.track.sample(progress).unwrap_or(self.from)
+    }
+}
+
+/// The clips of one motion, sorted by start time
+struct MotionTrack<T: Animatable> {
+    target: Box<dyn TimelineTarget<T>>,
+    clips: Vec<Clip<T>>,
+    active: Option<usize>,
+}
+
+impl<T: Animatable> MotionTrack<T> {
+    /// Gets the clip that controls the motion at `time`: the latest one started by then
+    fn clip_at(&self, time: Duration) -> Option<usize> {
+        self.clips.iter().rposition(|clip| clip.start <= time)
+    }
+
+    /// Gets the value the motion has at `time` according to the clips added so far
+    fn value_at(&self, time: Duration) -> T {
+        self.clip_at(time).map_or_else(
+            || {
+                self.clips
+                    .first()
+                    .map_or_else(|| self.target.value(), |clip| clip.from)
+            },
+            |index| self.clips[index].sample(time),
+        )
+    }
+
+    /// Adds a clip starting at `start` and returns its end
+    ///
+    /// Clips after it start from whatever the new clip leaves, so they are rebuilt in order.
+    fn insert(&mut self, start: Duration, build: ClipBuilder<T>) -> Duration {
+        let from = self.value_at(start);
+        let at = self.clips.partition_point(|other| other.start <= start);
+        self.clips.insert(at, Clip::new(start, from, build));
+        for index in at + 1..self.clips.len() {
+            let from = self.clips[index - 1].sample(self.clips[index].start);
+            self.clips[index].rebuild(from);
+        }
+        self.active = None;
+        self.clips[at].end()
+    }
+
+    /// Gets the end of the last clip
+    fn end(&self) -> Duration {
+        self.clips.iter().map(Clip::end).max().unwrap_or_default()
+    }
+}
+
+/// Type-erased [`MotionTrack`] so one timeline can hold motions of different types
+trait AnyTrack {
+    fn end(&self) -> Duration;
+    fn render(&mut self, time: Duration);
+    fn release(&mut self);
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Animatable> AnyTrack for MotionTrack<T> {
+    fn end(&self) -> Duration {
+        MotionTrack::end(self)
+    }
+
+    fn render(&mut self, time: Duration) {
+        // Before its first clip a track shows that clip's starting value
+        let Some(index) = self
+            .clip_at(time)
+            .or_else(|| (!self.clips.is_empty()).then_some(0))
+        else {
+            return;
+        };
+        if self.active != Some(index) {
+            self.target.activate(self.clips[index].track.clone());
+            self.active = Some(index);
+        }
+        self.target
+            .seek(time.saturating_sub(self.clips[index].start));
+    }
+
+    fn release(&mut self) {
+        if self.active.take().is_some() {
+            self.target.release();
+        }
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Plays animations of many motions on one shared clock
+///
+/// Each motion is added once with [`Timeline::track`]; animations for it are then placed
+/// with [`Timeline::to`], [`Timeline::keyframes`] and [`Timeline::sequence`]. Where clips
+/// of the same motion overlap, the one that starts later takes over, starting from the
+/// value the motion has at that moment. Clips play once: their delay shifts their start,
+/// while loop modes and callbacks are ignored.
+///
+/// While the timeline controls a motion, the motion is in scrub mode and follows the
+/// timeline's time. Motions are handed back when playback finishes or on
+/// [`Timeline::release`].
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+/// use dioxus_motion::timeline::TimelineTarget;
+///
+/// # fn build(x: impl TimelineTarget<f32> + 'static, opacity: impl TimelineTarget<f32> + 'static)
+/// #     -> Result<Timeline, TimelineError> {
+/// let fast = || AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(300))));
+///
+/// let mut timeline = Timeline::new();
+/// let x = timeline.track(x);
+/// let opacity = timeline.track(opacity);
+/// timeline
+///     .to(x, 100.0, fast(), Position::end())?
+///     .add_label("fade", "-=100ms")?
+///     .to(opacity, 0.0, fast(), "fade")?
+///     .to(x, 0.0, fast(), "+=200ms")?;
+/// assert_eq!(timeline.duration(), Duration::from_millis(1000));
+///
+/// timeline.play();
+/// # Ok(timeline)
+/// # }
+/// ```
+#[derive(Default)]
+pub struct Timeline {
+    tracks: Vec<Box<dyn AnyTrack>>,
+    labels: HashMap<String, Duration>,
+    /// End of the last clip
+    duration: Duration,
+    /// Start and end of the most recently added clip
+    previous: (Duration, Duration),
+    time: Duration,
+    playing: bool,
+    reversed: bool,
+    scheduler: Option<SchedulerLink>,
+}
+
+impl Timeline {
+    /// Creates an empty timeline
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wakes `link` whenever playback starts
+    pub fn with_scheduler(mut self, link: SchedulerLink) -> Self {
+        self.scheduler = Some(link);
+        self
+    }
+
+    /// Adds a motion to the timeline and returns the handle used to animate it
+    pub fn track<T, M>(&mut self, motion: M) -> Track<T>
+    where
+        T: Animatable,
+        M: TimelineTarget<T> + 'static,
+    {
+        self.tracks.push(Box::new(MotionTrack {
+            target: Box::new(motion),
+            clips: Vec::new(),
+            active: None,
+        }));
+        Track {
+            index: self.tracks.len() - 1,
+            _value: PhantomData,
+        }
+    }
+
+    /// Animates a motion to `target` with `config`, starting at `position`
+    ///
+    /// Springs last until they settle over the distance from the motion's value at that
+    /// point of the timeline.
+    pub fn to<T, P>(
+        &mut self,
+        track: Track<T>,
+        target: T,
+        config: AnimationConfig,
+        position: P,
+    ) -> Result<&mut Self, TimelineError>
+    where
+        T: Animatable,
+        P: TryInto<Position>,
+        P::Error: Into<TimelineError>,
+    {
+        let start = self.resolve(position)? + config.delay;
+        let mode = config.mode;
+        Ok(self.insert(
+            track,
+            start,
+            Box::new(move |from| KeyframeAnimation::starting_at(from).then(target, mode.clone())),
+        ))
+    }
+
+    /// Plays a keyframe animation on a motion, starting at `position`
+    pub fn keyframes<T, P>(
+        &mut self,
+        track: Track<T>,
+        animation: KeyframeAnimation<T>,
+        position: P,
+    ) -> Result<&mut Self, TimelineError>
+    where
+        T: Animatable,
+        P: TryInto<Position>,
+        P::Error: Into<TimelineError>,
+    {
+        let start = self.resolve(position)? + animation.delay;
+        let animation = KeyframeAnimation {
+            loop_mode: None,
+            delay: Duration::ZERO,
+            direction: KeyframeDirection::Normal,
+            on_complete: None,
+            ..animation
+        };
+        Ok(self.insert(track, start, Box::new(move |_| animation.clone())))
+    }
+
+    /// Plays the steps of a sequence on a motion, starting at `position`
+    pub fn sequence<T, P>(
+        &mut self,
+        track: Track<T>,
+        sequence: &AnimationSequence<T>,
+        position: P,
+    ) -> Result<&mut Self, TimelineError>
+    where
+        T: Animatable,
+        P: TryInto<Position>,
+        P::Error: Into<TimelineError>,
+    {
+        let start = self.resolve(position)?;
+        // Call steps take no time and callbacks don't run on timelines
+        let steps: Vec<_> = sequence
+            .steps()
+            .iter()
+            .filter(|step| !matches!(step.kind, StepKind::Call(_)))
+            .cloned()
+            .collect();
+        let build = move |from| {
+            let mut value = from;
+            let mut animation = KeyframeAnimation::starting_at(from);
+            for step in &steps {
+                if !step.config.delay.is_zero() {
+                    // Hold the previous value while the step waits
+                    animation =
+                        animation.then(value, AnimationMode::Tween(Tween::new(step.config.delay)));
+                }
+                value = step.target_from(value);
+                animation = animation.then(value, step.config.mode.clone());
+            }
+            animation
+        };
+        Ok(self.insert(track, start, Box::new(build)))
+    }
+
+    /// Adds a named label at `position` for later clips and [`Timeline::seek_label`]
+    pub fn add_label<P>(
+        &mut self,
+        name: impl Into<String>,
+        position: P,
+    ) -> Result<&mut Self, TimelineError>
+    where
+        P: TryInto<Position>,
+        P::Error: Into<TimelineError>,
+    {
+        let time = self.resolve(position)?;
+        self.labels.insert(name.into(), time);
+        Ok(self)
+    }
+
+    /// Gets the time of a label
+    pub fn label_time(&self, name: &str) -> Option<Duration> {
+        self.labels.get(name).copied()
+    }
+
+    /// Gets the time at which `position` is on this timeline
+    pub fn resolve<P>(&self, position: P) -> Result<Duration, TimelineError>
+    where
+        P: TryInto<Position>,
+        P::Error: Into<TimelineError>,
+    {
+        let position = position.try_into().map_err(Into::into)?;
+        let anchor = match &position.anchor {
+            Anchor::Start => Duration::ZERO,
+            Anchor::End => self.duration,
+            Anchor::PreviousStart => self.previous.0,
+            Anchor::PreviousEnd => self.previous.1,
+            Anchor::Label(name) => self
+                .label_time(name)
+                .ok_or_else(|| TimelineError::UnknownLabel(name.clone()))?,
+        };
+        Ok(if position.before {
+            anchor.saturating_sub(position.offset)
+        } else {
+            anchor + position.offset
+        })
+    }
+
+    fn motion_track<T: Animatable>(&mut self, track: Track<T>) -> &mut MotionTrack<T> {
+        self.tracks[track.index]
+            .as_any_mut()
+            .downcast_mut::<MotionTrack<T>>()
+            .unwrap_or_else(|| unreachable!("tracks are typed by their handle"))
+    }
+
+    fn insert<T: Animatable>(
+        &mut self,
+        track: Track<T>,
+        start: Duration,
+        build: ClipBuilder<T>,
+    ) -> &mut Self {
+        let end = self.motion_track(track).insert(start, build);
+        self.previous = (start, end);
+        // Rebuilt clips may have changed length
+        self.duration = self
+            .tracks
+            .iter()
+            .map(|track| track.end())
+            .max()
+            .unwrap_or_default();
+        self
+    }
+
+    /// Gets the length of the timeline, up to the end of its last clip
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Gets the current time of the timeline
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// Gets the current time as a fraction of the duration (0.0 to 1.0)
+    pub fn progress(&self) -> f32 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        self.time.as_secs_f32() / self.duration.as_secs_f32()
+    }
+
+    /// Returns `true` while the timeline advances on [`Timeline::update`]
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Returns `true` if the timeline plays backwards
+    pub fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+
+    /// Plays forwards from the current time
+    pub fn play(&mut self) {
+        self.reversed = false;
+        self.start_playing();
+    }
+
+    /// Plays backwards from the current time towards the start
+    pub fn reverse(&mut self) {
+        self.reversed = true;
+        self.start_playing();
+    }
+
+    /// Plays forwards from the start
+    pub fn restart(&mut self) {
+        self.seek(Duration::ZERO);
+        self.play();
+    }
+
+    /// Freezes the timeline and all its motions at the current time
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Continues playing in the current direction
+    pub fn resume(&mut self) {
+        self.start_playing();
+    }
+
+    fn start_playing(&mut self) {
+        self.playing = true;
+        if let Some(link) = &self.scheduler {
+            link.wake();
+        }
+    }
+
+    /// Jumps to `time` and shows every motion at that point
+    pub fn seek(&mut self, time: Duration) {
+        self.time = time.min(self.duration);
+        self.render();
+    }
+
+    /// Jumps to `progress` (0.0 to 1.0) of the duration
+    pub fn seek_progress(&mut self, progress: f32) {
+        self.seek(self.duration.mul_f32(progress.clamp(0.0, 1.0)));
+    }
+
+    /// Jumps to a label
+    pub fn seek_label(&mut self, name: &str) -> Result<(), TimelineError> {
+        let time = self
+            .label_time(name)
+            .ok_or_else(|| TimelineError::UnknownLabel(name.to_string()))?;
+        self.seek(time);
+        Ok(())
+    }
+
+    /// Advances the timeline by `dt` seconds in its current direction
+    ///
+    /// Returns `true` while it keeps playing. Reaching either end stops playback and
+    /// releases the motions.
+    pub fn update(&mut self, dt: f32) -> bool {
+        if !self.playing {
+            return false;
+        }
+
+        let step = Duration::from_secs_f32(dt.abs());
+        self.time = if self.reversed {
+            self.time.saturating_sub(step)
+        } else {
+            (self.time + step).min(self.duration)
+        };
+        self.render();
+
+        let finished = if self.reversed {
+            self.time.is_zero()
+        } else {
+            self.time >= self.duration
+        };
+        if finished {
+            self.playing = false;
+            self.release();
+        }
+        self.playing
+    }
+
+    /// Hands every motion back, stopped at its current value
+    ///
+    /// Motions may be animated on their own again afterwards; the timeline takes them over
+    /// again on the next seek or frame.
+    pub fn release(&mut self) {
+        for track in &mut self.tracks {
+            track.release();
+        }
+    }
+
+    fn render(&mut self) {
+        for track in &mut self.tracks {
+            track.render(self.time);
+        }
+    }
+}
+
+/// Creates a timeline driven by the shared frame scheduler
+///
+/// `build` adds the timeline's tracks and clips once. The timeline then advances with every
+/// other motion in the app's frame loop while it plays, and costs nothing while paused.
+///
+/// # Example
+///
+/// ```no_run
+/// use dioxus_motion::prelude::*;
+/// use dioxus::prelude::*;
+///
+/// fn app() -> Element {
+///     let x = use_motion(0.0f32);
+///     let opacity = use_motion(1.0f32);
+///     let mut timeline = use_timeline(move |timeline| {
+///         let tween = AnimationConfig::new(AnimationMode::Tween(Tween::default()));
+///         let x = timeline.track(x);
+///         let opacity = timeline.track(opacity);
+///         timeline
+///             .to(x, 100.0, tween.clone(), Position::end())
+///             .and_then(|timeline| timeline.to(opacity, 0.0, tween, "<+=100ms"))
+///             .expect("valid positions");
+///     });
+///
+///     rsx! {
+///         div {
+///             onclick: move |_| timeline.write().restart(),
+///             style: "transform: translateX({x.get_value()}px); opacity: {opacity.get_value()}",
+///         }
+///     }
+/// }
+/// ```
+pub fn use_timeline(build: impl FnOnce(&mut Timeline)) -> Signal<Timeline> {
+    let scheduler = use_frame_scheduler();
+    let link = use_hook(|| scheduler.reserve());
+
+    let state = use_signal({
+        let link = link.clone();
+        move || {
+            let mut timeline = Timeline::new().with_scheduler(link);
+            build(&mut timeline);
+            timeline
+        }
+    });
+
+    use_hook({
+        let scheduler = scheduler.clone();
+        let link = link.clone();
+        move || {
+            let mut state = state;
+            scheduler.attach(&link, move |dt| {
+                if !state.peek().is_playing() {
+                    return false;
+                }
+                state.write().update(dt)
+            });
+        }
+    });
+
+    use_drop(move || scheduler.unregister(&link));
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::animations::spring::Spring;
+    use crate::animations::transform::Transform;
+    use crate::motion::Motion;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Motion shared between a test and the timeline
+    struct Shared<T: Animatable + Send + 'static>(Rc<RefCell<Motion<T>>>);
+
+    impl<T: Animatable + Send + 'static> TimelineTarget<T> for Shared<T> {
+        fn value(&self) -> T {
+            self.0.borrow().get_value()
+        }
+
+        fn activate(&mut self, track: Arc<KeyframeTrack<T>>) {
+            let mut motion = self.0.borrow_mut();
+            motion.set_scrubbing(true);
+            motion.animate_track(track);
+        }
+
+        fn seek(&mut self, time: Duration) {
+            self.0.borrow_mut().seek_time(time);
+        }
+
+        fn release(&mut self) {
+            let mut motion = self.0.borrow_mut();
+            motion.stop();
+            motion.set_scrubbing(false);
+        }
+    }
+
+    fn motion<T: Animatable + Send + 'static>(initial: T) -> (Rc<RefCell<Motion<T>>>, Shared<T>) {
+        let motion = Rc::new(RefCell::new(Motion::new(initial)));
+        (motion.clone(), Shared(motion))
+    }
+
+    fn tween(millis: u64) -> AnimationConfig {
+        AnimationConfig::new(AnimationMode::Tween(Tween::new(ms(millis))))
+    }
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_position_parsing() {
+        let parse = |input: &str| input.parse::<Position>().unwrap();
+        assert_eq!(parse("500ms"), Position::from(ms(500)));
+        assert_eq!(parse("<"), Position::with_previous());
+        assert_eq!(parse(">"), Position::after_previous());
+        assert_eq!(parse("+=0.2s"), Position::end().plus(ms(200)));
+        assert_eq!(parse("-=100ms"), Position::end().minus(ms(100)));
+        assert_eq!(parse("<+=50ms"), Position::with_previous().plus(ms(50)));
+        assert_eq!(parse("intro"), Position::label("intro"));
+        assert_eq!(parse("intro-=1s"), Position::label("intro").minus(ms(1000)));
+
+        assert!(matches!(
+            "+=fast".parse::<Position>(),
+            Err(TimelineError::InvalidPosition { .. })
+        ));
+        assert!("".parse::<Position>().is_err());
+    }
+
+    #[test]
+    fn test_relative_positions_labels_and_overlaps() {
+        let (_, a) = motion(0.0f32);
+        let (_, b) = motion(0.0f32);
+        let mut timeline = Timeline::new();
+        let a = timeline.track(a);
+        let b = timeline.track(b);
+
+        let start = |timeline: &Timeline| timeline.previous.0;
+        timeline.to(a, 1.0, tween(100), Position::end()).unwrap();
+        assert_eq!(start(&timeline), ms(0));
+        timeline.to(b, 1.0, tween(100), "+=50ms").unwrap();
+        assert_eq!(start(&timeline), ms(150));
+        timeline.to(a, 0.0, tween(100), "<").unwrap();
+        assert_eq!(start(&timeline), ms(150));
+        timeline.to(b, 0.0, tween(100), "-=40ms").unwrap();
+        assert_eq!(start(&timeline), ms(210));
+        timeline.add_label("outro", ">").unwrap();
+        assert_eq!(timeline.label_time("outro"), Some(ms(310)));
+        timeline.to(a, 2.0, tween(100), "outro+=10ms").unwrap();
+        assert_eq!(start(&timeline), ms(320));
+        timeline.to(b, 2.0, tween(100), ms(30)).unwrap();
+        assert_eq!(start(&timeline), ms(30));
+        timeline.to(b, 3.0, tween(10), "<+=20ms").unwrap();
+        assert_eq!(start(&timeline), ms(50));
+
+        // Delays shift the clip, positions before zero clamp to the start
+        let mut delayed = tween(100);
+        delayed.delay = ms(25);
+        timeline.to(a, 3.0, delayed, "outro").unwrap();
+        assert_eq!(start(&timeline), ms(335));
+        timeline.to(a, 3.0, tween(10), "-=10s").unwrap();
+        assert_eq!(start(&timeline), ms(0));
+
+        assert_eq!(timeline.duration(), ms(435));
+        assert_eq!(
+            timeline.to(a, 0.0, tween(10), "missing").err(),
+            Some(TimelineError::UnknownLabel("missing".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parallel_tracks_of_different_types() {
+        let (x, x_target) = motion(0.0f32);
+        let (transform, transform_target) = motion(Transform::identity());
+        let mut timeline = Timeline::new();
+        let x_track = timeline.track(x_target);
+        let transform_track = timeline.track(transform_target);
+        timeline
+            .to(x_track, 100.0, tween(200), Position::end())
+            .unwrap()
+            .to(
+                transform_track,
+                Transform::new(40.0, 0.0, 2.0, 0.0),
+                tween(100),
+                "<+=100ms",
+            )
+            .unwrap();
+        assert_eq!(timeline.duration(), ms(200));
+
+        timeline.seek(ms(150));
+        assert!((x.borrow().current - 75.0).abs() < 0.01);
+        assert!((transform.borrow().current.x - 20.0).abs() < 0.01);
+        assert!((transform.borrow().current.scale - 1.5).abs() < 0.01);
+        assert!(x.borrow().is_scrubbing());
+
+        // Seeking back before a clip shows where it starts from
+        timeline.seek(ms(50));
+        assert!((x.borrow().current - 25.0).abs() < 0.01);
+        assert_eq!(transform.borrow().current.x, 0.0);
+    }
+
+    #[test]
+    fn test_overlapping_clips_hand_over_on_the_same_motion() {
+        let (x, target) = motion(0.0f32);
+        let mut timeline = Timeline::new();
+        let track = timeline.track(target);
+        timeline
+            .to(track, 100.0, tween(200), Position::end())
+            .unwrap()
+            .to(track, 0.0, tween(100), "-=100ms")
+            .unwrap();
+        assert_eq!(timeline.duration(), ms(200));
+
+        // The second clip takes over halfway, from the value the first one had then
+        timeline.seek(ms(100));
+        assert!((x.borrow().current - 50.0).abs() < 0.01);
+        timeline.seek(ms(150));
+        assert!((x.borrow().current - 25.0).abs() < 0.01);
+        timeline.seek(ms(200));
+        assert_eq!(x.borrow().current, 0.0);
+        timeline.seek(ms(40));
+        assert!((x.borrow().current - 20.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_earlier_clips_move_the_start_of_later_ones() {
+        let (x, target) = motion(0.0f32);
+        let mut timeline = Timeline::new();
+        let track = timeline.track(target);
+        timeline
+            .to(track, 100.0, tween(500), "500ms")
+            .unwrap()
+            .to(track, 50.0, tween(0), "0s")
+            .unwrap();
+        assert_eq!(timeline.duration(), ms(1000));
+
+        // The later clip now starts from where the instant clip left the motion
+        timeline.seek(ms(499));
+        assert_eq!(x.borrow().current, 50.0);
+        timeline.seek(ms(501));
+        assert!((x.borrow().current - 50.1).abs() < 0.01);
+        timeline.seek(ms(750));
+        assert!((x.borrow().current - 75.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_play_pause_reverse_and_release() {
+        let (x, target) = motion(0.0f32);
+        let mut timeline = Timeline::new();
+        let track = timeline.track(target);
+        timeline
+            .to(track, 100.0, tween(100), Position::end())
+            .unwrap();
+
+        assert!(!timeline.update(0.01));
+        timeline.play();
+        assert!(timeline.update(0.05));
+        assert!((x.borrow().current - 50.0).abs() < 0.01);
+        assert!(!x.borrow().is_running());
+
+        timeline.pause();
+        assert!(!timeline.update(0.05));
+        assert!((timeline.progress() - 0.5).abs() < 1e-6);
+
+        timeline.reverse();
+        assert!(timeline.update(0.025));
+        assert!((x.borrow().current - 25.0).abs() < 0.01);
+        assert!(!timeline.update(0.05));
+        assert_eq!(x.borrow().current, 0.0);
+        assert!(!x.borrow().is_scrubbing());
+
+        timeline.play();
+        while timeline.update(1.0 / 60.0) {}
+        assert_eq!(x.borrow().current, 100.0);
+        assert!(!x.borrow().is_scrubbing() && !x.borrow().is_running());
+
+        timeline.restart();
+        assert_eq!(x.borrow().current, 0.0);
+        assert!(timeline.is_playing() && !timeline.is_reversed());
+        timeline.seek_label("missing").unwrap_err();
+    }
+
+    #[test]
+    fn test_keyframe_and_sequence_clips() {
+        let (x, target) = motion(10.0f32);
+        let mut timeline = Timeline::new();
+        let track = timeline.track(target);
+
+        let keyframes = KeyframeAnimation::builder(ms(100))
+            .keyframe(0.0f32)
+            .keyframe(50.0)
+            .build()
+            .unwrap()
+            .with_loop(crate::prelude::LoopMode::Infinite);
+        let mut held = tween(100);
+        held.delay = ms(50);
+        let sequence = AnimationSequence::new()
+            .then(100.0f32, tween(100))
+            .then(0.0, held);
+        timeline
+            .keyframes(track, keyframes, Position::end())
+            .unwrap()
+            .add_label("sequence", Position::end())
+            .unwrap()
+            .sequence(track, &sequence, "sequence")
+            .unwrap();
+        assert_eq!(timeline.duration(), ms(350));
+
+        timeline.seek(ms(50));
+        assert!((x.borrow().current - 25.0).abs() < 0.01);
+        timeline.seek_label("sequence").unwrap();
+        assert_eq!(x.borrow().current, 50.0);
+        timeline.seek(ms(150));
+        assert!((x.borrow().current - 75.0).abs() < 0.01);
+        // Held during the second step's delay
+        timeline.seek(ms(225));
+        assert_eq!(x.borrow().current, 100.0);
+        timeline.seek(ms(300));
+        assert!((x.borrow().current - 50.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_spring_clip_lasts_until_settled() {
+        let (_, target) = motion(0.0f32);
+        let spring = Spring::default();
+        let mut timeline = Timeline::new();
+        let track = timeline.track(target);
+        timeline
+            .to(
+                track,
+                100.0,
+                AnimationConfig::new(AnimationMode::Spring(spring)),
+                Position::end(),
+            )
+            .unwrap();
+        assert_eq!(
+            timeline.duration(),
+            spring.settling_time(-100.0, 0.0, f32::epsilon())
+        );
+    }
+}
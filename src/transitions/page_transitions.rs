@@ -282,6 +282,7 @@ fn FromRouteToCurrent<R: AnimatableRoute>(route_type: PhantomData<R>, from: R, t
                         damping: 25.0,
                         mass: 1.0,
                         velocity: 0.0,
+                        ..Default::default()
                     })
                 });
                 (